@@ -0,0 +1,55 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+use textual_data_cleaner::french::FrenchSubstitutions;
+use textual_data_cleaner::{substitutions, Clean, Substitute};
+
+const SAMPLE: &str = "« L’œuvre d’Æsop » — 42 pages, 中文 texte, Ελληνικά, кириллица… ";
+
+fn document(size: usize) -> String {
+    SAMPLE.repeat(size / SAMPLE.len() + 1)
+}
+
+fn substitute(c: &mut Criterion) {
+    let wide_ranges = Substitute::new(&substitutions! {
+        'a'..='z' => "",
+        '\u{4e00}'..='\u{9fff}' => "?",
+        '\u{370}'..='\u{3ff}' => "",
+        '\u{400}'..='\u{4ff}' => "",
+    });
+    let strings = Substitute::new(&substitutions! {
+        "..." => "…",
+        "--" => "—",
+        "oe" => "œ",
+        "&nbsp;" => " ",
+        '…' => "...",
+    });
+    let french = FrenchSubstitutions::new();
+
+    let mut group = c.benchmark_group("substitute");
+
+    for size in [1 << 20, 2 << 20, 4 << 20] {
+        let input = document(size);
+        group.throughput(Throughput::Bytes(input.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("wide_ranges", size), &input, |b, input| {
+            b.iter(|| wide_ranges.clean(&mut input.clone()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("strings", size), &input, |b, input| {
+            b.iter(|| strings.clean(&mut input.clone()))
+        });
+
+        group.bench_with_input(BenchmarkId::new("french", size), &input, |b, input| {
+            b.iter(|| french.clean(&mut input.clone()))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = substitute
+}
+criterion_main!(benches);
@@ -0,0 +1,215 @@
+//! Transliteration of unicode text to ASCII
+
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::{is_nfkd_quick, IsNormalized, UnicodeNormalization};
+
+use crate::clean::Clean;
+use crate::substitutions::{Substitute, Substitution, SubstitutionsTable};
+
+mod tables;
+pub use tables::*;
+
+/// What happens to the chars that can't be transliterated to ASCII
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum AsciiFallback {
+    /// Keeps the chars, the output may not be ASCII
+    Keep,
+    /// Removes the chars
+    #[default]
+    Remove,
+    /// Replaces each char with a String
+    Replace(String),
+}
+
+/// Substitutions of the chars whose compatibility decomposition (NFKD) is made of ASCII chars,
+/// nonspacing marks and chars already substituted by `explicit`
+///
+/// The diacritics are stripped (`é` to `e`), the ligatures and the compatibility forms are
+/// expanded (`ﬁ` to `fi`, `Ａ` to `A`) and the accented letters of other scripts follow the
+/// transliteration of their base letter (`ά` to `a`).
+pub fn decompositions(explicit: &Substitute) -> SubstitutionsTable {
+    let mut table = SubstitutionsTable::new();
+
+    // The planes 3 to 13 and the private use planes hold no decomposable char nor mark
+    let scanned = ('\u{80}'..='\u{2ffff}').chain('\u{e0000}'..='\u{e0fff}');
+
+    for c in scanned {
+        if explicit.substitution(c).is_some() {
+            continue;
+        }
+
+        // Fast path for the chars without decomposition, only the nonspacing marks are stripped
+        if is_nfkd_quick(std::iter::once(c)) == IsNormalized::Yes {
+            if get_general_category(c) == GeneralCategory::NonspacingMark {
+                table.push(Substitution::char(c, ""));
+            }
+
+            continue;
+        }
+
+        let mut transliteration = String::new();
+
+        let transliterable = std::iter::once(c).nfkd().all(|decomposed| {
+            if decomposed.is_ascii() {
+                transliteration.push(decomposed);
+            } else if let Some(replacement) = explicit.substitution(decomposed) {
+                transliteration.push_str(replacement);
+            } else if get_general_category(decomposed) != GeneralCategory::NonspacingMark {
+                return false;
+            }
+
+            true
+        });
+
+        if transliterable {
+            table.push(Substitution::char(c, &transliteration));
+        }
+    }
+
+    table
+}
+
+/// Transliteration table of [`AsciiTransliterator`], built once since scanning the decompositions
+/// of every char is slow
+fn transliteration_table() -> &'static SubstitutionsTable {
+    static TABLE: OnceLock<SubstitutionsTable> = OnceLock::new();
+
+    TABLE.get_or_init(|| {
+        let mut table = SubstitutionsTable::from(latin_letters());
+        table.merge(&greek());
+        table.merge(&cyrillic());
+        table.merge(&punctuation().into());
+
+        let explicit = Substitute::new(&table);
+        table.merge(&decompositions(&explicit));
+
+        table
+    })
+}
+
+/// Converts a String to a best-effort ASCII representation
+///
+/// The latin letters that don't decompose, the Greek and Cyrillic letters and the typographic
+/// punctuation are transliterated with the tables of this module, then the diacritics are
+/// stripped and the ligatures expanded (see [`decompositions`]). The remaining chars are handled
+/// by the [`AsciiFallback`], removed by default.
+///
+/// ```
+/// use textual_data_cleaner::ascii::{AsciiFallback, AsciiTransliterator};
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("Ærøskøbing, «Žluťoučký» ﬁlm — Αθήνα, Москва 北京");
+/// AsciiTransliterator::new().clean(&mut data);
+///
+/// assert_eq!(data, "AEroskobing, \"Zlutoucky\" film - Athina, Moskva ");
+///
+/// let mut data = String::from("北京");
+/// AsciiTransliterator::new()
+///     .fallback(AsciiFallback::Replace("?".to_string()))
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "??");
+/// ```
+#[derive(Clone)]
+pub struct AsciiTransliterator {
+    table: SubstitutionsTable,
+    substitute: Substitute,
+}
+
+impl AsciiTransliterator {
+    pub fn new() -> AsciiTransliterator {
+        let table = transliteration_table().clone();
+
+        AsciiTransliterator {
+            substitute: Self::substitute(&table, &AsciiFallback::default()),
+            table,
+        }
+    }
+
+    pub fn fallback(mut self, fallback: AsciiFallback) -> Self {
+        self.substitute = Self::substitute(&self.table, &fallback);
+        self
+    }
+
+    fn substitute(table: &SubstitutionsTable, fallback: &AsciiFallback) -> Substitute {
+        let mut table = table.clone();
+
+        match fallback {
+            AsciiFallback::Keep => {}
+            AsciiFallback::Remove => table.push(Substitution::range('\u{80}'..=char::MAX, "")),
+            AsciiFallback::Replace(replacement) => {
+                table.push(Substitution::range('\u{80}'..=char::MAX, replacement))
+            }
+        }
+
+        Substitute::new(&table)
+    }
+}
+
+impl Default for AsciiTransliterator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clean for AsciiTransliterator {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        self.substitute.clean(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn transliterate(input: &str) -> String {
+        let mut data = input.to_string();
+        AsciiTransliterator::new().clean(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_diacritics_and_ligatures() {
+        assert_eq!(transliterate("Ça été déjà reçu"), "Ca ete deja recu");
+        assert_eq!(transliterate("e\u{301}te\u{301}"), "ete");
+        assert_eq!(
+            transliterate("Œuvre, ﬁﬂ, ĳ, Straße, Łódź"),
+            "OEuvre, fifl, ij, Strasse, Lodz"
+        );
+        assert_eq!(transliterate("Ｆｕｌｌ ½ ²"), "Full 1/2 2");
+        assert_eq!(transliterate("\u{1d400}\u{1d41b} \u{1f130}"), "Ab A");
+    }
+
+    #[test]
+    fn test_greek_and_cyrillic() {
+        assert_eq!(transliterate("Ψυχή Θεός"), "Psychi Theos");
+        assert_eq!(
+            transliterate("Щука, Юрий, Ёлка, Київ"),
+            "Shchuka, Yuriy, Yolka, Kiyiv"
+        );
+    }
+
+    #[test]
+    fn test_punctuation() {
+        assert_eq!(
+            transliterate("“Hello” – ‘world’… 5\u{a0}€ ©\u{200b}"),
+            "\"Hello\" - 'world'... 5 EUR (c)"
+        );
+    }
+
+    #[test]
+    fn test_fallback() {
+        let mut data = String::from("a 漢 b");
+        AsciiTransliterator::new()
+            .fallback(AsciiFallback::Keep)
+            .clean(&mut data);
+
+        assert_eq!(data, "a 漢 b");
+    }
+}
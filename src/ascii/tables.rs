@@ -0,0 +1,168 @@
+use crate::substitutions;
+use crate::substitutions::{Substitution, SubstitutionsList, SubstitutionsTable};
+
+/// Lowercase Greek letters and their latin transliteration
+const GREEK: [(char, &str); 25] = [
+    ('α', "a"),
+    ('β', "v"),
+    ('γ', "g"),
+    ('δ', "d"),
+    ('ε', "e"),
+    ('ζ', "z"),
+    ('η', "i"),
+    ('θ', "th"),
+    ('ι', "i"),
+    ('κ', "k"),
+    ('λ', "l"),
+    ('μ', "m"),
+    ('ν', "n"),
+    ('ξ', "x"),
+    ('ο', "o"),
+    ('π', "p"),
+    ('ρ', "r"),
+    ('σ', "s"),
+    ('ς', "s"),
+    ('τ', "t"),
+    ('υ', "y"),
+    ('φ', "f"),
+    ('χ', "ch"),
+    ('ψ', "ps"),
+    ('ω', "o"),
+];
+
+/// Lowercase Russian, Ukrainian and Belarusian letters and their latin transliteration
+const CYRILLIC: [(char, &str); 38] = [
+    ('а', "a"),
+    ('б', "b"),
+    ('в', "v"),
+    ('г', "g"),
+    ('д', "d"),
+    ('е', "e"),
+    ('ё', "yo"),
+    ('ж', "zh"),
+    ('з', "z"),
+    ('и', "i"),
+    ('й', "y"),
+    ('к', "k"),
+    ('л', "l"),
+    ('м', "m"),
+    ('н', "n"),
+    ('о', "o"),
+    ('п', "p"),
+    ('р', "r"),
+    ('с', "s"),
+    ('т', "t"),
+    ('у', "u"),
+    ('ф', "f"),
+    ('х', "kh"),
+    ('ц', "ts"),
+    ('ч', "ch"),
+    ('ш', "sh"),
+    ('щ', "shch"),
+    ('ъ', ""),
+    ('ы', "y"),
+    ('ь', ""),
+    ('э', "e"),
+    ('ю', "yu"),
+    ('я', "ya"),
+    ('є', "ye"),
+    ('і', "i"),
+    ('ї', "yi"),
+    ('ґ', "g"),
+    ('ў', "u"),
+];
+
+/// Builds the substitutions of lowercase letters and of their uppercase forms, the first letter
+/// of the transliteration being capitalized
+fn with_uppercase(letters: &[(char, &str)]) -> SubstitutionsTable {
+    let mut table = SubstitutionsTable::new();
+
+    for (letter, transliteration) in letters {
+        table.push(Substitution::char(*letter, transliteration));
+
+        let mut uppercase = letter.to_uppercase();
+
+        if let (Some(uppercase), None) = (uppercase.next(), uppercase.next()) {
+            if uppercase != *letter {
+                let mut chars = transliteration.chars();
+                let capitalized: String = chars
+                    .next()
+                    .map(|first| first.to_ascii_uppercase())
+                    .into_iter()
+                    .chain(chars)
+                    .collect();
+
+                table.push(Substitution::char(uppercase, &capitalized));
+            }
+        }
+    }
+
+    table
+}
+
+/// Transliteration of the Greek letters
+pub fn greek() -> SubstitutionsTable {
+    with_uppercase(&GREEK)
+}
+
+/// Transliteration of the Cyrillic letters
+pub fn cyrillic() -> SubstitutionsTable {
+    with_uppercase(&CYRILLIC)
+}
+
+/// Latin letters and ligatures that don't decompose into ASCII letters
+pub fn latin_letters() -> SubstitutionsList {
+    substitutions! {
+        'ß' => "ss",
+        'ẞ' => "SS",
+        'æ' => "ae",
+        'Æ' => "AE",
+        'œ' => "oe",
+        'Œ' => "OE",
+        'ø' => "o",
+        'Ø' => "O",
+        'ł' => "l",
+        'Ł' => "L",
+        'đ' => "d",
+        'Đ' => "D",
+        'ð' => "d",
+        'Ð' => "D",
+        'þ' => "th",
+        'Þ' => "Th",
+        'ħ' => "h",
+        'Ħ' => "H",
+        'ı' => "i",
+        'ĸ' => "q",
+        'ŋ' => "ng",
+        'Ŋ' => "NG",
+        'ŧ' => "t",
+        'Ŧ' => "T",
+        'ŀ' => "l",
+        'Ŀ' => "L",
+        'ƒ' => "f",
+        'ſ' => "s",
+    }
+}
+
+/// Typographic punctuation, symbols and spaces
+pub fn punctuation() -> SubstitutionsList {
+    substitutions! {
+        '‘', '’', '‚', '‛', '′', '‹', '›' => "'",
+        '“', '”', '„', '‟', '″', '«', '»' => "\"",
+        '\u{2010}'..='\u{2015}', '−' => "-",
+        '…' => "...",
+        '•' => "*",
+        '·' => ".",
+        '×' => "x",
+        '÷', '⁄' => "/",
+        '¿' => "?",
+        '¡' => "!",
+        '©' => "(c)",
+        '®' => "(R)",
+        '™' => "TM",
+        '€' => "EUR",
+        '£' => "GBP",
+        '\u{a0}', '\u{2000}'..='\u{200a}', '\u{202f}', '\u{205f}', '\u{3000}' => " ",
+        '\u{ad}', '\u{200b}'..='\u{200d}', '\u{2060}', '\u{feff}' => "",
+    }
+}
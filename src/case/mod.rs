@@ -0,0 +1,330 @@
+//! Case conversion and case folding
+
+use serde::Deserialize;
+use unicode_general_category::{get_general_category, GeneralCategory};
+
+use crate::normalize::Normalize;
+
+mod folding;
+use folding::FULL_CASE_FOLDING;
+
+/// Case applied by a [`CaseNormalizer`]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Case {
+    /// `hello world`
+    Lower,
+    /// `HELLO WORLD`
+    Upper,
+    /// Full unicode case folding, for case-insensitive matching: `Straße` to `strasse`
+    Fold,
+    /// `Hello World`
+    Title,
+    /// `Hello world. Goodbye world.`
+    Sentence,
+}
+
+/// Locale of the case mappings
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CaseLocale {
+    /// Default unicode case mappings
+    #[default]
+    Default,
+    /// Turkish and Azerbaijani mappings of the dotted and dotless i: `I` to `ı` and `İ` to `i`
+    Turkish,
+}
+
+/// Chars ending a sentence
+const SENTENCE_TERMINATORS: [char; 4] = ['.', '!', '?', '…'];
+
+/// Chars that may follow a sentence terminator before the space, such as closing quotes
+const SENTENCE_CLOSERS: [char; 7] = ['"', '\'', '»', '”', '’', ')', ']'];
+
+/// Apostrophes, a letter following an apostrophe inside a word doesn't start a new word: `l'été`
+const APOSTROPHES: [char; 2] = ['\'', '’'];
+
+/// Returns true if the char belongs to the word before it: letters, digits and combining marks
+fn is_word_char(c: char) -> bool {
+    c.is_alphanumeric()
+        || matches!(
+            get_general_category(c),
+            GeneralCategory::NonspacingMark
+                | GeneralCategory::SpacingMark
+                | GeneralCategory::EnclosingMark
+        )
+}
+
+fn to_lowercase(data: &str, locale: CaseLocale) -> String {
+    match locale {
+        CaseLocale::Default => data.to_lowercase(),
+        CaseLocale::Turkish => data
+            .replace("I\u{307}", "i")
+            .replace('İ', "i")
+            .replace('I', "ı")
+            .to_lowercase(),
+    }
+}
+
+fn to_uppercase(data: &str, locale: CaseLocale) -> String {
+    match locale {
+        CaseLocale::Default => data.to_uppercase(),
+        CaseLocale::Turkish => data.replace('i', "İ").to_uppercase(),
+    }
+}
+
+fn fold_case(data: &str, locale: CaseLocale) -> String {
+    let data = match locale {
+        CaseLocale::Default => data.to_string(),
+        CaseLocale::Turkish => data
+            .replace("I\u{307}", "i")
+            .replace('İ', "i")
+            .replace('I', "ı"),
+    };
+
+    let mut output = String::with_capacity(data.len());
+
+    for c in data.chars() {
+        match FULL_CASE_FOLDING.binary_search_by_key(&c, |(folded, _)| *folded) {
+            Ok(index) => output.push_str(FULL_CASE_FOLDING[index].1),
+            Err(_) => output.extend(c.to_lowercase()),
+        }
+    }
+
+    output
+}
+
+/// Pushes the titlecase form of a lowercase char: its uppercase form, or the titlecase form of
+/// the digraphs (`ǆ` to `ǅ`) and of the chars whose uppercase form is made of several chars
+/// (`ß` to `Ss`, `ﬁ` to `Fi`)
+fn push_titlecase(output: &mut String, c: char, locale: CaseLocale) {
+    match c {
+        'ǆ' => output.push('ǅ'),
+        'ǉ' => output.push('ǈ'),
+        'ǌ' => output.push('ǋ'),
+        'ǳ' => output.push('ǲ'),
+        'i' if locale == CaseLocale::Turkish => output.push('İ'),
+        c => {
+            let mut uppercase = c.to_uppercase();
+
+            if let Some(first) = uppercase.next() {
+                output.push(first);
+                output.extend(uppercase.flat_map(char::to_lowercase));
+            }
+        }
+    }
+}
+
+fn to_titlecase(data: &str, locale: CaseLocale) -> String {
+    let lowercase = to_lowercase(data, locale);
+    let mut output = String::with_capacity(lowercase.len());
+    let mut previous_char: Option<char> = None;
+    let mut before_previous_char: Option<char> = None;
+
+    for c in lowercase.chars() {
+        // An apostrophe is inside a word (`l'été`) only when it follows a word char, otherwise
+        // it is an opening quote (`'hello'`)
+        let after_apostrophe = previous_char.is_some_and(|p| APOSTROPHES.contains(&p))
+            && before_previous_char.is_some_and(is_word_char);
+        let starts_word =
+            c.is_alphabetic() && !previous_char.is_some_and(is_word_char) && !after_apostrophe;
+
+        if starts_word {
+            push_titlecase(&mut output, c, locale);
+        } else {
+            output.push(c);
+        }
+
+        before_previous_char = previous_char;
+        previous_char = Some(c);
+    }
+
+    output
+}
+
+fn to_sentence_case(data: &str, locale: CaseLocale) -> String {
+    let lowercase = to_lowercase(data, locale);
+    let mut output = String::with_capacity(lowercase.len());
+    let mut starts_sentence = true;
+    let mut after_terminator = false;
+
+    for c in lowercase.chars() {
+        if starts_sentence && c.is_alphanumeric() {
+            push_titlecase(&mut output, c, locale);
+            starts_sentence = false;
+            continue;
+        }
+
+        output.push(c);
+
+        if SENTENCE_TERMINATORS.contains(&c) {
+            after_terminator = true;
+        } else if c.is_whitespace() {
+            starts_sentence |= after_terminator;
+            after_terminator = false;
+        } else if !SENTENCE_CLOSERS.contains(&c) {
+            after_terminator = false;
+        }
+    }
+
+    output
+}
+
+/// Converts the case of a String
+///
+/// The lowercase mappings handle the Greek final sigma (`ΟΔΥΣΣΕΥΣ` to `οδυσσευς`), the case
+/// folding maps it to `σ` like the other variant forms. The title case and the sentence case
+/// lowercase the text before capitalizing the first letter of each word, or of each sentence.
+///
+/// ```
+/// use textual_data_cleaner::case::{Case, CaseLocale, CaseNormalizer};
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("Die STRAẞE");
+/// CaseNormalizer::new(Case::Fold).normalize(&mut data);
+///
+/// assert_eq!(data, "die strasse");
+///
+/// let mut data = String::from("iSTANBUL'DA bir gün. DİYARBAKIR");
+/// CaseNormalizer::new(Case::Sentence)
+///     .locale(CaseLocale::Turkish)
+///     .normalize(&mut data);
+///
+/// assert_eq!(data, "İstanbul'da bir gün. Diyarbakır");
+/// ```
+#[derive(Clone)]
+pub struct CaseNormalizer {
+    case: Case,
+    locale: CaseLocale,
+}
+
+impl CaseNormalizer {
+    pub fn new(case: Case) -> CaseNormalizer {
+        CaseNormalizer {
+            case,
+            locale: CaseLocale::Default,
+        }
+    }
+
+    pub fn locale(mut self, locale: CaseLocale) -> Self {
+        self.locale = locale;
+        self
+    }
+}
+
+impl Normalize for CaseNormalizer {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        *data = match self.case {
+            Case::Lower => to_lowercase(data, self.locale),
+            Case::Upper => to_uppercase(data, self.locale),
+            Case::Fold => fold_case(data, self.locale),
+            Case::Title => to_titlecase(data, self.locale),
+            Case::Sentence => to_sentence_case(data, self.locale),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(case: Case, locale: CaseLocale, input: &str) -> String {
+        let mut data = input.to_string();
+        CaseNormalizer::new(case)
+            .locale(locale)
+            .normalize(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_folding_table_is_sorted() {
+        assert!(FULL_CASE_FOLDING.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_lower_and_upper() {
+        let default = CaseLocale::Default;
+
+        assert_eq!(convert(Case::Lower, default, "ÉTÉ Straße"), "été straße");
+        assert_eq!(convert(Case::Upper, default, "été straße"), "ÉTÉ STRASSE");
+        assert_eq!(
+            convert(Case::Lower, default, "ΟΔΥΣΣΕΥΣ ΣΟΦΟΣ"),
+            "οδυσσευς σοφος"
+        );
+        assert_eq!(
+            convert(Case::Upper, default, "istanbul ılık"),
+            "ISTANBUL ILIK"
+        );
+    }
+
+    #[test]
+    fn test_fold() {
+        let default = CaseLocale::Default;
+
+        assert_eq!(
+            convert(Case::Fold, default, "Straße STRAẞE ﬁn µ"),
+            "strasse strasse fin μ"
+        );
+        assert_eq!(
+            convert(Case::Fold, default, "ΟΔΥΣΣΕΥΣ οδυσσευς"),
+            "οδυσσευσ οδυσσευσ"
+        );
+        assert_eq!(
+            convert(Case::Fold, default, "\u{13a0} \u{ab70}"),
+            "\u{13a0} \u{13a0}"
+        );
+    }
+
+    #[test]
+    fn test_turkish_locale() {
+        let turkish = CaseLocale::Turkish;
+
+        assert_eq!(convert(Case::Lower, turkish, "DİYARBAKIR"), "diyarbakır");
+        assert_eq!(convert(Case::Lower, turkish, "I\u{307}zmir"), "izmir");
+        assert_eq!(
+            convert(Case::Upper, turkish, "istanbul ılık"),
+            "İSTANBUL ILIK"
+        );
+        assert_eq!(convert(Case::Fold, turkish, "Işık İzmir"), "ışık izmir");
+        assert_eq!(convert(Case::Title, turkish, "izmir ırmak"), "İzmir Irmak");
+    }
+
+    #[test]
+    fn test_title() {
+        let default = CaseLocale::Default;
+
+        assert_eq!(
+            convert(Case::Title, default, "l'ÉTÉ de jean-paul, don’t 19TH"),
+            "L'été De Jean-Paul, Don’t 19th"
+        );
+        assert_eq!(
+            convert(Case::Title, default, "e\u{301}te\u{301} ǆungla ßa"),
+            "E\u{301}te\u{301} ǅungla Ssa"
+        );
+        assert_eq!(convert(Case::Title, default, "ΣΟΦΟΣ ΟΔΟΣ"), "Σοφος Οδος");
+        assert_eq!(
+            convert(Case::Title, default, "'hello world', ’TIS l’été"),
+            "'Hello World', ’Tis L’été"
+        );
+    }
+
+    #[test]
+    fn test_sentence() {
+        let default = CaseLocale::Default;
+
+        assert_eq!(
+            convert(
+                Case::Sentence,
+                default,
+                "HELLO WORLD. how ARE you?  «FINE!» thanks… 1.5 KG"
+            ),
+            "Hello world. How are you?  «Fine!» Thanks… 1.5 kg"
+        );
+        assert_eq!(
+            convert(Case::Sentence, default, " \"quoted.\" next"),
+            " \"Quoted.\" Next"
+        );
+    }
+}
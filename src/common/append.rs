@@ -1,9 +1,14 @@
+use std::sync::Arc;
+
+use super::context::{Condition, InsertionContext};
+use super::occurrences::{insert_char_at, Occurrences};
 use crate::normalize::Normalize;
 
 /// Append a character after a character in a String.
 pub struct AppendAfterChar {
     target_char: char,
     char_to_insert: char,
+    occurrences: Occurrences,
 }
 
 impl AppendAfterChar {
@@ -11,56 +16,102 @@ impl AppendAfterChar {
         Self {
             target_char,
             char_to_insert,
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl Normalize for AppendAfterChar {
     type Data = String;
 
     fn normalize(&self, data: &mut Self::Data) {
-        data.rfind(self.target_char)
-            .map(|index| data.insert(index + self.target_char.len_utf8(), self.char_to_insert));
+        let indices: Vec<usize> = self
+            .occurrences
+            .select(data, self.target_char)
+            .into_iter()
+            .map(|index| index + self.target_char.len_utf8())
+            .collect();
+
+        insert_char_at(data, &indices, self.char_to_insert);
     }
 }
 
+/// Append a character after a character in a String based on a condition.
+///
+/// The condition receives the char following the target char, the target char and the char to insert,
+/// or the whole [`InsertionContext`] of the target char when built with [`AppendAfterCharIf::with_context`].
+#[derive(Clone)]
 pub struct AppendAfterCharIf {
     target_char: char,
     char_to_insert: char,
-    condition: fn(Option<char>, char, char) -> bool,
+    condition: Condition,
+    occurrences: Occurrences,
 }
 
 impl AppendAfterCharIf {
-    pub fn new(
-        target_char: char,
-        char_to_insert: char,
-        condition: fn(Option<char>, char, char) -> bool,
-    ) -> Self {
+    pub fn new<F>(target_char: char, char_to_insert: char, condition: F) -> Self
+    where
+        F: Fn(Option<char>, char, char) -> bool + Send + Sync + 'static,
+    {
+        Self::with_context(
+            target_char,
+            char_to_insert,
+            move |context: &InsertionContext| {
+                condition(
+                    context.next_char(),
+                    context.target_char(),
+                    context.char_to_insert(),
+                )
+            },
+        )
+    }
+
+    /// Builds the cleaner from a condition receiving the whole [`InsertionContext`] of the target char
+    pub fn with_context<F>(target_char: char, char_to_insert: char, condition: F) -> Self
+    where
+        F: Fn(&InsertionContext) -> bool + Send + Sync + 'static,
+    {
         Self {
             target_char,
             char_to_insert,
-            condition,
+            condition: Arc::new(condition),
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl Normalize for AppendAfterCharIf {
     type Data = String;
 
     fn normalize(&self, data: &mut Self::Data) {
-        data.rfind(self.target_char).map(|index| {
-            let next_char_index = index + self.target_char.len_utf8();
-
-            let next_char = if next_char_index <= data.len() {
-                data[index + self.target_char.len_utf8()..].chars().nth(0)
-            } else {
-                None
-            };
-
-            if (self.condition)(next_char, self.target_char, self.char_to_insert) {
-                data.insert(index + self.target_char.len_utf8(), self.char_to_insert);
-            }
-        });
+        let indices: Vec<usize> = self
+            .occurrences
+            .select(data, self.target_char)
+            .into_iter()
+            .filter(|index| {
+                (self.condition)(&InsertionContext::new(
+                    data,
+                    *index,
+                    self.target_char,
+                    self.char_to_insert,
+                ))
+            })
+            .map(|index| index + self.target_char.len_utf8())
+            .collect();
+
+        insert_char_at(data, &indices, self.char_to_insert);
     }
 }
 
@@ -69,6 +120,7 @@ impl Normalize for AppendAfterCharIf {
 pub struct AppendAfterCharIfDifferent {
     target_char: char,
     char_to_insert: char,
+    occurrences: Occurrences,
 }
 
 impl AppendAfterCharIfDifferent {
@@ -76,8 +128,15 @@ impl AppendAfterCharIfDifferent {
         Self {
             target_char,
             char_to_insert,
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl From<AppendAfterCharIfDifferent> for AppendAfterCharIf {
@@ -90,7 +149,7 @@ impl From<AppendAfterCharIfDifferent> for AppendAfterCharIf {
             }
         };
 
-        Self::new(other.target_char, other.char_to_insert, func)
+        Self::new(other.target_char, other.char_to_insert, func).occurrences(other.occurrences)
     }
 }
 
@@ -174,7 +233,7 @@ mod tests {
 
         let mut data = String::from(":eee");
 
-        let func = |next_char: Option<char>, _matching_char: char, replacement: char| {
+        let func = |next_char: Option<char>, _matching_char: char, _replacement: char| {
             if let Some(next_char) = next_char {
                 !next_char.is_ascii_whitespace()
             } else {
@@ -221,4 +280,88 @@ mod tests {
 
         assert_eq!(" : eee", data);
     }
+
+    #[test]
+    fn test_append_every_occurrence() {
+        let mut data = String::from("a;b;c");
+
+        AppendAfterChar::new(';', ' ').normalize(&mut data);
+
+        assert_eq!(data, "a; b; c");
+
+        let mut data = String::from("œaœ");
+
+        AppendAfterChar::new('œ', '’').normalize(&mut data);
+
+        assert_eq!(data, "œ’aœ’");
+    }
+
+    #[test]
+    fn test_append_if_every_occurrence() {
+        let mut data = String::from("a;b; c;");
+
+        AppendAfterCharIfDifferent::new(';', ' ').normalize(&mut data);
+
+        assert_eq!(data, "a; b; c; ");
+
+        let mut data = String::from("«a«««");
+
+        AppendAfterCharIfDifferent::new('«', '\u{a0}').normalize(&mut data);
+
+        assert_eq!(data, "«\u{a0}a«\u{a0}«\u{a0}«\u{a0}");
+    }
+
+    #[test]
+    fn test_append_occurrences() {
+        let append = |occurrences: Occurrences| {
+            let mut data = String::from("a;b;c;");
+            AppendAfterCharIfDifferent::new(';', ' ')
+                .occurrences(occurrences)
+                .normalize(&mut data);
+            data
+        };
+
+        assert_eq!(append(Occurrences::First), "a; b;c;");
+        assert_eq!(append(Occurrences::Last), "a;b;c; ");
+        assert_eq!(append(Occurrences::Nth(1)), "a;b; c;");
+        assert_eq!(append(Occurrences::Nth(3)), "a;b;c;");
+
+        let mut data = String::from("a;b;c;");
+        AppendAfterChar::new(';', ' ')
+            .occurrences(Occurrences::Nth(2))
+            .normalize(&mut data);
+
+        assert_eq!(data, "a;b;c; ");
+    }
+
+    #[test]
+    fn test_append_if_with_captured_state() {
+        let punctuation = String::from(".!");
+        let append_if = AppendAfterCharIf::new('.', ' ', move |next_char, _, _| {
+            next_char.is_some_and(|c| !c.is_whitespace() && !punctuation.contains(c))
+        });
+
+        let mut data = String::from("a.b.. c.");
+        append_if.normalize(&mut data);
+
+        assert_eq!(data, "a. b.. c.");
+    }
+
+    #[test]
+    fn test_append_if_with_context() {
+        // Inserts a space after a period ending a word of at least two letters
+        let append_if = AppendAfterCharIf::with_context('.', ' ', |context: &InsertionContext| {
+            context
+                .previous_chars(2)
+                .filter(|c| c.is_alphabetic())
+                .count()
+                == 2
+                && context.is_word_boundary_after()
+        });
+
+        let mut data = String::from("M.Dupont.Fin");
+        append_if.clone().normalize(&mut data);
+
+        assert_eq!(data, "M.Dupont. Fin");
+    }
 }
@@ -0,0 +1,141 @@
+use std::sync::Arc;
+
+/// Condition of the conditional insertion cleaners
+pub(crate) type Condition = Arc<dyn Fn(&InsertionContext) -> bool + Send + Sync>;
+
+/// Surroundings of an occurrence of the target char of an insertion cleaner
+///
+/// ```
+/// use textual_data_cleaner::common::{AppendAfterCharIf, InsertionContext};
+/// use textual_data_cleaner::Normalize;
+///
+/// // Inserts a space after a comma, unless it is a decimal separator
+/// let append = AppendAfterCharIf::with_context(',', ' ', |context: &InsertionContext| {
+///     let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+///
+///     !(is_digit(context.previous_char()) && is_digit(context.next_char()))
+///         && context.next_char() != Some(' ')
+/// });
+///
+/// let mut data = String::from("1,5 kg,2 l, 3 m");
+/// append.normalize(&mut data);
+///
+/// assert_eq!(data, "1,5 kg, 2 l, 3 m");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InsertionContext<'a> {
+    text: &'a str,
+    index: usize,
+    target_char: char,
+    char_to_insert: char,
+}
+
+impl<'a> InsertionContext<'a> {
+    /// Context of the target char found at the byte `index` of `text`
+    pub fn new(text: &'a str, index: usize, target_char: char, char_to_insert: char) -> Self {
+        Self {
+            text,
+            index,
+            target_char,
+            char_to_insert,
+        }
+    }
+
+    pub fn target_char(&self) -> char {
+        self.target_char
+    }
+
+    pub fn char_to_insert(&self) -> char {
+        self.char_to_insert
+    }
+
+    /// Text before the target char
+    pub fn before(&self) -> &'a str {
+        &self.text[..self.index]
+    }
+
+    /// Text after the target char
+    pub fn after(&self) -> &'a str {
+        &self.text[self.index + self.target_char.len_utf8()..]
+    }
+
+    pub fn previous_char(&self) -> Option<char> {
+        self.before().chars().next_back()
+    }
+
+    pub fn next_char(&self) -> Option<char> {
+        self.after().chars().next()
+    }
+
+    /// Up to `n` chars before the target char, the nearest first
+    pub fn previous_chars(&self, n: usize) -> impl Iterator<Item = char> + 'a {
+        self.before().chars().rev().take(n)
+    }
+
+    /// Up to `n` chars after the target char, the nearest first
+    pub fn next_chars(&self, n: usize) -> impl Iterator<Item = char> + 'a {
+        self.after().chars().take(n)
+    }
+
+    /// Returns true if a word starts or ends right before the target char
+    pub fn is_word_boundary_before(&self) -> bool {
+        is_word_boundary(self.previous_char(), Some(self.target_char))
+    }
+
+    /// Returns true if a word starts or ends right after the target char
+    pub fn is_word_boundary_after(&self) -> bool {
+        is_word_boundary(Some(self.target_char), self.next_char())
+    }
+}
+
+fn is_word_boundary(previous_char: Option<char>, next_char: Option<char>) -> bool {
+    let is_word_char = |c: Option<char>| c.is_some_and(char::is_alphanumeric);
+
+    is_word_char(previous_char) != is_word_char(next_char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insertion_context() {
+        let text = "lœrem: ipsum";
+        let context = InsertionContext::new(text, 6, ':', ' ');
+
+        assert_eq!(context.before(), "lœrem");
+        assert_eq!(context.after(), " ipsum");
+        assert_eq!(context.previous_char(), Some('m'));
+        assert_eq!(context.next_char(), Some(' '));
+        assert_eq!(context.previous_chars(3).collect::<String>(), "mer");
+        assert_eq!(context.next_chars(3).collect::<String>(), " ip");
+        assert_eq!(context.previous_chars(10).count(), 5);
+    }
+
+    #[test]
+    fn test_word_boundaries() {
+        let text = "l'eau";
+        let context = InsertionContext::new(text, 1, '\'', ' ');
+
+        assert!(context.is_word_boundary_before());
+        assert!(context.is_word_boundary_after());
+
+        let text = "eau";
+        let context = InsertionContext::new(text, 1, 'a', ' ');
+
+        assert!(!context.is_word_boundary_before());
+        assert!(!context.is_word_boundary_after());
+
+        let context = InsertionContext::new(text, 0, 'e', ' ');
+        assert!(context.is_word_boundary_before());
+
+        let context = InsertionContext::new(text, 2, 'u', ' ');
+        assert!(context.is_word_boundary_after());
+
+        let text = "!";
+        let context = InsertionContext::new(text, 0, '!', ' ');
+
+        assert!(!context.is_word_boundary_before());
+        assert!(!context.is_word_boundary_after());
+    }
+}
@@ -0,0 +1,26 @@
+use crate::clean::Clean;
+
+/// Removes all unicode control characters
+pub struct ControlCharRemover;
+
+impl Clean for ControlCharRemover {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        data.retain(|c| !c.is_control());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_control_char_remover() {
+        let mut data = String::from("lorem\u{0}\t ipsum\u{7f}\n");
+
+        ControlCharRemover.clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum");
+    }
+}
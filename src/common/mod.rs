@@ -1,8 +1,20 @@
+mod occurrences;
+pub use occurrences::*;
+
 mod strip;
 pub use strip::*;
 
+mod context;
+pub use context::*;
+
 mod prepend;
 pub use prepend::*;
 
 mod append;
 pub use append::*;
+
+mod space_around;
+pub use space_around::*;
+
+mod control;
+pub use control::*;
@@ -0,0 +1,74 @@
+use serde::Deserialize;
+
+/// Occurrences of the target char an insertion cleaner acts on
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Occurrences {
+    /// Every occurrence
+    #[default]
+    All,
+    /// The first occurrence only
+    First,
+    /// The last occurrence only
+    Last,
+    /// The nth occurrence only, starting from 0
+    Nth(usize),
+}
+
+impl Occurrences {
+    /// Returns the byte indices of the selected occurrences of a char
+    pub(crate) fn select(&self, data: &str, target_char: char) -> Vec<usize> {
+        let mut indices = data.match_indices(target_char).map(|(index, _)| index);
+
+        match self {
+            Occurrences::All => indices.collect(),
+            Occurrences::First => indices.next().into_iter().collect(),
+            Occurrences::Last => indices.next_back().into_iter().collect(),
+            Occurrences::Nth(n) => indices.nth(*n).into_iter().collect(),
+        }
+    }
+}
+
+/// Inserts a char at each of the given byte indices, which must be sorted, in a single pass
+pub(crate) fn insert_char_at(data: &mut String, indices: &[usize], char_to_insert: char) {
+    if indices.is_empty() {
+        return;
+    }
+
+    let mut output = String::with_capacity(data.len() + indices.len() * char_to_insert.len_utf8());
+    let mut last_index = 0;
+
+    for index in indices {
+        output.push_str(&data[last_index..*index]);
+        output.push(char_to_insert);
+        last_index = *index;
+    }
+
+    output.push_str(&data[last_index..]);
+    *data = output;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select_occurrences() {
+        let data = "a;bœ;c;";
+
+        assert_eq!(Occurrences::All.select(data, ';'), vec![1, 5, 7]);
+        assert_eq!(Occurrences::First.select(data, ';'), vec![1]);
+        assert_eq!(Occurrences::Last.select(data, ';'), vec![7]);
+        assert_eq!(Occurrences::Nth(1).select(data, ';'), vec![5]);
+        assert_eq!(Occurrences::Nth(3).select(data, ';'), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn test_insert_char_at() {
+        let mut data = String::from("aœb");
+
+        insert_char_at(&mut data, &[0, 1, 3, 4], '·');
+
+        assert_eq!(data, "·a·œ·b·");
+    }
+}
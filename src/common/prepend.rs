@@ -1,10 +1,14 @@
+use std::sync::Arc;
+
+use super::context::{Condition, InsertionContext};
+use super::occurrences::{insert_char_at, Occurrences};
 use crate::normalize::Normalize;
-use crate::utils::get_previous_char;
 
 /// Prepend a character before a character in a String.
 pub struct PrependBeforeChar {
     target_char: char,
     char_to_insert: char,
+    occurrences: Occurrences,
 }
 
 impl PrependBeforeChar {
@@ -12,51 +16,96 @@ impl PrependBeforeChar {
         Self {
             target_char,
             char_to_insert,
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl Normalize for PrependBeforeChar {
     type Data = String;
 
     fn normalize(&self, data: &mut Self::Data) {
-        data.rfind(self.target_char)
-            .map(|index| data.insert(index, self.char_to_insert));
+        let indices = self.occurrences.select(data, self.target_char);
+
+        insert_char_at(data, &indices, self.char_to_insert);
     }
 }
 
 /// Prepend a character before a character in a String based on a condition.
+///
+/// The condition receives the char preceding the target char, the target char and the char to insert,
+/// or the whole [`InsertionContext`] of the target char when built with [`PrependBeforeCharIf::with_context`].
+#[derive(Clone)]
 pub struct PrependBeforeCharIf {
     target_char: char,
     char_to_insert: char,
-    condition: fn(Option<char>, char, char) -> bool,
+    condition: Condition,
+    occurrences: Occurrences,
 }
 
 impl PrependBeforeCharIf {
-    pub fn new(
-        target_char: char,
-        char_to_insert: char,
-        condition: fn(Option<char>, char, char) -> bool,
-    ) -> Self {
+    pub fn new<F>(target_char: char, char_to_insert: char, condition: F) -> Self
+    where
+        F: Fn(Option<char>, char, char) -> bool + Send + Sync + 'static,
+    {
+        Self::with_context(
+            target_char,
+            char_to_insert,
+            move |context: &InsertionContext| {
+                condition(
+                    context.previous_char(),
+                    context.target_char(),
+                    context.char_to_insert(),
+                )
+            },
+        )
+    }
+
+    /// Builds the cleaner from a condition receiving the whole [`InsertionContext`] of the target char
+    pub fn with_context<F>(target_char: char, char_to_insert: char, condition: F) -> Self
+    where
+        F: Fn(&InsertionContext) -> bool + Send + Sync + 'static,
+    {
         Self {
             target_char,
             char_to_insert,
-            condition,
+            condition: Arc::new(condition),
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl Normalize for PrependBeforeCharIf {
     type Data = String;
 
     fn normalize(&self, data: &mut Self::Data) {
-        data.rfind(self.target_char).map(|index| {
-            let previous_char = &data[..index].chars().last();
-
-            if (self.condition)(*previous_char, self.target_char, self.char_to_insert) {
-                data.insert(index, self.char_to_insert);
-            }
-        });
+        let indices: Vec<usize> = self
+            .occurrences
+            .select(data, self.target_char)
+            .into_iter()
+            .filter(|index| {
+                (self.condition)(&InsertionContext::new(
+                    data,
+                    *index,
+                    self.target_char,
+                    self.char_to_insert,
+                ))
+            })
+            .collect();
+
+        insert_char_at(data, &indices, self.char_to_insert);
     }
 }
 
@@ -65,6 +114,7 @@ impl Normalize for PrependBeforeCharIf {
 pub struct PrependBeforeCharIfDifferent {
     target_char: char,
     char_to_insert: char,
+    occurrences: Occurrences,
 }
 
 impl PrependBeforeCharIfDifferent {
@@ -72,8 +122,15 @@ impl PrependBeforeCharIfDifferent {
         Self {
             target_char,
             char_to_insert,
+            occurrences: Occurrences::All,
         }
     }
+
+    /// Sets the occurrences of the target char to act on, all of them by default
+    pub fn occurrences(mut self, occurrences: Occurrences) -> Self {
+        self.occurrences = occurrences;
+        self
+    }
 }
 
 impl From<PrependBeforeCharIfDifferent> for PrependBeforeCharIf {
@@ -86,7 +143,7 @@ impl From<PrependBeforeCharIfDifferent> for PrependBeforeCharIf {
             }
         };
 
-        Self::new(other.target_char, other.char_to_insert, func)
+        Self::new(other.target_char, other.char_to_insert, func).occurrences(other.occurrences)
     }
 }
 
@@ -213,4 +270,67 @@ mod tests {
 
         assert_eq!(" ;", data);
     }
+
+    #[test]
+    fn test_prepend_every_occurrence() {
+        let mut data = String::from("a;b;c");
+
+        PrependBeforeChar::new(';', ' ').normalize(&mut data);
+
+        assert_eq!(data, "a ;b ;c");
+
+        let mut data = String::from("œaœ");
+
+        PrependBeforeChar::new('œ', 'é').normalize(&mut data);
+
+        assert_eq!(data, "éœaéœ");
+    }
+
+    #[test]
+    fn test_prepend_if_every_occurrence() {
+        let mut data = String::from("a:b :c:");
+
+        PrependBeforeCharIfDifferent::new(':', ' ').normalize(&mut data);
+
+        assert_eq!(data, "a :b :c :");
+
+        let mut data = String::from("»a»»");
+
+        PrependBeforeCharIfDifferent::new('»', '\u{a0}').normalize(&mut data);
+
+        assert_eq!(data, "\u{a0}»a\u{a0}»\u{a0}»");
+    }
+
+    #[test]
+    fn test_prepend_occurrences() {
+        let prepend = |occurrences: Occurrences| {
+            let mut data = String::from("a;b;c;");
+            PrependBeforeCharIfDifferent::new(';', ' ')
+                .occurrences(occurrences)
+                .normalize(&mut data);
+            data
+        };
+
+        assert_eq!(prepend(Occurrences::First), "a ;b;c;");
+        assert_eq!(prepend(Occurrences::Last), "a;b;c ;");
+        assert_eq!(prepend(Occurrences::Nth(1)), "a;b ;c;");
+        assert_eq!(prepend(Occurrences::Nth(3)), "a;b;c;");
+    }
+
+    #[test]
+    fn test_prepend_if_with_context() {
+        // Inserts a space before a colon unless it separates digits
+        let prepend_if =
+            PrependBeforeCharIf::with_context(':', ' ', |context: &InsertionContext| {
+                let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+
+                !(is_digit(context.previous_char()) && is_digit(context.next_char()))
+                    && context.previous_char() != Some(' ')
+            });
+
+        let mut data = String::from("heure:12:30");
+        prepend_if.normalize(&mut data);
+
+        assert_eq!(data, "heure :12:30");
+    }
 }
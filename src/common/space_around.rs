@@ -0,0 +1,247 @@
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::normalize::Normalize;
+
+/// Spacing required on one side of a char
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum Spacing {
+    /// Leaves the existing spacing untouched
+    #[default]
+    Keep,
+    /// Removes the spaces
+    None,
+    /// A space ` ` (U+0020)
+    Space,
+    /// A no-break space (U+00A0)
+    NoBreakSpace,
+    /// A narrow no-break space (U+202F)
+    NarrowNoBreakSpace,
+}
+
+impl Spacing {
+    /// Returns the space char of the spacing, if any
+    pub fn space_char(&self) -> Option<char> {
+        match self {
+            Spacing::Keep | Spacing::None => None,
+            Spacing::Space => Some(' '),
+            Spacing::NoBreakSpace => Some('\u{a0}'),
+            Spacing::NarrowNoBreakSpace => Some('\u{202f}'),
+        }
+    }
+}
+
+/// Returns true if the char is a whitespace that doesn't break the line
+fn is_inline_space(c: char) -> bool {
+    c.is_whitespace()
+        && !matches!(
+            c,
+            '\n' | '\u{b}' | '\u{c}' | '\r' | '\u{85}' | '\u{2028}' | '\u{2029}'
+        )
+}
+
+/// Enforces the spacing before and after a set of chars
+///
+/// The spaces surrounding a target char are replaced by the required spacing, a missing space is
+/// inserted and extra spaces are removed. When two target chars follow each other the spacing
+/// before the second one wins over the spacing after the first one, unless it is [`Spacing::Keep`].
+///
+/// The beginning and the end of the String as well as the line breaks are left untouched.
+/// With [`SpaceAround::replace_only`] the missing spaces are not inserted, only the existing
+/// ones are replaced or removed.
+///
+/// ```
+/// use textual_data_cleaner::common::{SpaceAround, Spacing};
+/// use textual_data_cleaner::Normalize;
+///
+/// let space_around = SpaceAround::new()
+///     .rule([':'], Spacing::NoBreakSpace, Spacing::Space)
+///     .rule([','], Spacing::None, Spacing::Space);
+///
+/// let mut data = String::from("lorem  :ipsum ,dolor,sit");
+/// space_around.normalize(&mut data);
+///
+/// assert_eq!(data, "lorem\u{a0}: ipsum, dolor, sit");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SpaceAround {
+    rules: HashMap<char, (Spacing, Spacing)>,
+    replace_only: bool,
+}
+
+impl SpaceAround {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the spacing before and after the chars, replacing their previous rule
+    pub fn rule<I: IntoIterator<Item = char>>(
+        mut self,
+        chars: I,
+        before: Spacing,
+        after: Spacing,
+    ) -> Self {
+        for c in chars {
+            self.rules.insert(c, (before, after));
+        }
+        self
+    }
+
+    /// Sets the spacing before the chars
+    pub fn before<I: IntoIterator<Item = char>>(mut self, chars: I, spacing: Spacing) -> Self {
+        for c in chars {
+            self.rules.entry(c).or_default().0 = spacing;
+        }
+        self
+    }
+
+    /// Sets the spacing after the chars
+    pub fn after<I: IntoIterator<Item = char>>(mut self, chars: I, spacing: Spacing) -> Self {
+        for c in chars {
+            self.rules.entry(c).or_default().1 = spacing;
+        }
+        self
+    }
+
+    /// Only replaces or removes the existing spaces, the missing ones are not inserted
+    pub fn replace_only(mut self) -> Self {
+        self.replace_only = true;
+        self
+    }
+
+    /// Returns the spacing required between two chars
+    fn spacing_between(&self, previous_char: char, next_char: char) -> Spacing {
+        let before = self
+            .rules
+            .get(&next_char)
+            .map_or(Spacing::Keep, |(before, _)| *before);
+
+        match before {
+            Spacing::Keep => self
+                .rules
+                .get(&previous_char)
+                .map_or(Spacing::Keep, |(_, after)| *after),
+            before => before,
+        }
+    }
+}
+
+impl Normalize for SpaceAround {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if self.rules.is_empty() {
+            return;
+        }
+
+        let mut output = String::with_capacity(data.len());
+        let mut previous_char: Option<char> = None;
+        let mut spaces_start = 0;
+        let mut changed = false;
+
+        for (i, c) in data.char_indices() {
+            if c.is_whitespace() {
+                continue;
+            }
+
+            let spaces = &data[spaces_start..i];
+
+            let spacing = match previous_char {
+                Some(previous_char) if spaces.chars().all(is_inline_space) => {
+                    self.spacing_between(previous_char, c)
+                }
+                _ => Spacing::Keep,
+            };
+
+            if spacing == Spacing::Keep || (self.replace_only && spaces.is_empty()) {
+                output.push_str(spaces);
+            } else {
+                let mut buffer = [0; 4];
+                let expected_spaces = spacing
+                    .space_char()
+                    .map_or("", |space_char| space_char.encode_utf8(&mut buffer));
+
+                changed |= spaces != expected_spaces;
+                output.push_str(expected_spaces);
+            }
+
+            output.push(c);
+            previous_char = Some(c);
+            spaces_start = i + c.len_utf8();
+        }
+
+        if changed {
+            output.push_str(&data[spaces_start..]);
+            *data = output;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_space_around_insert_and_replace() {
+        let space_around =
+            SpaceAround::new().rule(['!', '?'], Spacing::NarrowNoBreakSpace, Spacing::Space);
+
+        let mut data = String::from("oui!non  ?\u{a0}peut-être\u{202f}!");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, "oui\u{202f}! non\u{202f}? peut-être\u{202f}!");
+    }
+
+    #[test]
+    fn test_space_around_remove() {
+        let space_around = SpaceAround::new().rule(['(', '['], Spacing::Keep, Spacing::None);
+
+        let mut data = String::from("lorem ( ipsum)  [\u{a0}dolor]");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, "lorem (ipsum)  [dolor]");
+    }
+
+    #[test]
+    fn test_space_around_adjacent_targets() {
+        let space_around = SpaceAround::new()
+            .rule(['.'], Spacing::None, Spacing::Space)
+            .rule([')'], Spacing::None, Spacing::Space)
+            .before(['»'], Spacing::NoBreakSpace);
+
+        let mut data = String::from("a.b (c) .d...e.»");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, "a. b (c). d... e.\u{a0}»");
+    }
+
+    #[test]
+    fn test_space_around_edges_and_line_breaks() {
+        let space_around = SpaceAround::new().rule([':'], Spacing::Space, Spacing::Space);
+
+        let mut data = String::from(" :a\n:\nb: ");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, " : a\n:\nb : ");
+
+        let mut data = String::from("a : b");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, "a : b");
+    }
+
+    #[test]
+    fn test_space_around_replace_only() {
+        let space_around = SpaceAround::new()
+            .rule([';'], Spacing::NarrowNoBreakSpace, Spacing::Space)
+            .rule([','], Spacing::None, Spacing::Keep)
+            .replace_only();
+
+        let mut data = String::from("a;b  ;\u{a0}c , d;");
+        space_around.normalize(&mut data);
+
+        assert_eq!(data, "a;b\u{202f}; c, d;");
+    }
+}
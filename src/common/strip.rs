@@ -1,2 +1,286 @@
+use std::ops::RangeInclusive;
+use std::sync::Arc;
+
+use serde::Deserialize;
+use unicode_general_category::get_general_category;
+pub use unicode_general_category::GeneralCategory;
+
+use crate::clean::Clean;
+
+/// All the unicode general categories
+const GENERAL_CATEGORIES: [GeneralCategory; 30] = [
+    GeneralCategory::ClosePunctuation,
+    GeneralCategory::ConnectorPunctuation,
+    GeneralCategory::Control,
+    GeneralCategory::CurrencySymbol,
+    GeneralCategory::DashPunctuation,
+    GeneralCategory::DecimalNumber,
+    GeneralCategory::EnclosingMark,
+    GeneralCategory::FinalPunctuation,
+    GeneralCategory::Format,
+    GeneralCategory::InitialPunctuation,
+    GeneralCategory::LetterNumber,
+    GeneralCategory::LineSeparator,
+    GeneralCategory::LowercaseLetter,
+    GeneralCategory::MathSymbol,
+    GeneralCategory::ModifierLetter,
+    GeneralCategory::ModifierSymbol,
+    GeneralCategory::NonspacingMark,
+    GeneralCategory::OpenPunctuation,
+    GeneralCategory::OtherLetter,
+    GeneralCategory::OtherNumber,
+    GeneralCategory::OtherPunctuation,
+    GeneralCategory::OtherSymbol,
+    GeneralCategory::ParagraphSeparator,
+    GeneralCategory::PrivateUse,
+    GeneralCategory::SpaceSeparator,
+    GeneralCategory::SpacingMark,
+    GeneralCategory::Surrogate,
+    GeneralCategory::TitlecaseLetter,
+    GeneralCategory::Unassigned,
+    GeneralCategory::UppercaseLetter,
+];
+
+/// Returns the general category matching an abbreviation such as `Mn` or `So`
+pub fn general_category_from_abbreviation(abbreviation: &str) -> Option<GeneralCategory> {
+    GENERAL_CATEGORIES
+        .iter()
+        .find(|category| category.abbreviation() == abbreviation)
+        .copied()
+}
+
+/// Part of a String from which chars are removed
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum StripPosition {
+    /// Removes the chars anywhere in the String
+    #[default]
+    Everywhere,
+    /// Removes the leading chars only
+    Start,
+    /// Removes the trailing chars only
+    End,
+    /// Removes the leading and trailing chars only
+    Both,
+}
+
+#[derive(Clone)]
+enum CharMatcher {
+    Chars(Vec<char>),
+    Range(RangeInclusive<char>),
+    Category(GeneralCategory),
+    Predicate(Arc<dyn Fn(char) -> bool + Send + Sync>),
+}
+
+impl CharMatcher {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            CharMatcher::Chars(chars) => chars.contains(&c),
+            CharMatcher::Range(range) => range.contains(&c),
+            CharMatcher::Category(category) => get_general_category(c) == *category,
+            CharMatcher::Predicate(predicate) => predicate(c),
+        }
+    }
+}
+
 /// Removes chars from a String
-pub struct Strip;
+///
+/// A char is removed as soon as it matches one of the chars, ranges, categories or predicates
+/// of the cleaner. Without any of them nothing is removed.
+///
+/// ```
+/// use textual_data_cleaner::common::{GeneralCategory, Strip, StripPosition};
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("e\u{301}te\u{301} 2022");
+/// Strip::new()
+///     .category(GeneralCategory::NonspacingMark)
+///     .range('0'..='9')
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "ete ");
+///
+/// let mut data = String::from("--lorem-ipsum--");
+/// Strip::new()
+///     .chars(['-'])
+///     .position(StripPosition::Both)
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "lorem-ipsum");
+/// ```
+#[derive(Clone, Default)]
+pub struct Strip {
+    matchers: Vec<CharMatcher>,
+    position: StripPosition,
+}
+
+impl Strip {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Removes the given chars
+    pub fn chars<I: IntoIterator<Item = char>>(mut self, chars: I) -> Self {
+        self.matchers
+            .push(CharMatcher::Chars(chars.into_iter().collect()));
+        self
+    }
+
+    /// Removes the chars within a range
+    pub fn range(mut self, range: RangeInclusive<char>) -> Self {
+        self.matchers.push(CharMatcher::Range(range));
+        self
+    }
+
+    /// Removes the chars of a unicode general category
+    pub fn category(mut self, category: GeneralCategory) -> Self {
+        self.matchers.push(CharMatcher::Category(category));
+        self
+    }
+
+    /// Removes the chars matching a predicate
+    pub fn predicate<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(char) -> bool + Send + Sync + 'static,
+    {
+        self.matchers
+            .push(CharMatcher::Predicate(Arc::new(predicate)));
+        self
+    }
+
+    /// Sets the part of the String from which chars are removed
+    pub fn position(mut self, position: StripPosition) -> Self {
+        self.position = position;
+        self
+    }
+
+    /// Returns true if the char is removed by this cleaner
+    pub fn matches(&self, c: char) -> bool {
+        self.matchers.iter().any(|matcher| matcher.matches(c))
+    }
+
+    fn strip_start(&self, data: &mut String) {
+        let start = data.find(|c| !self.matches(c)).unwrap_or(data.len());
+
+        data.drain(..start);
+    }
+
+    fn strip_end(&self, data: &mut String) {
+        let end = data
+            .char_indices()
+            .rev()
+            .find(|(_, c)| !self.matches(*c))
+            .map(|(i, c)| i + c.len_utf8())
+            .unwrap_or(0);
+
+        data.truncate(end);
+    }
+}
+
+impl Clean for Strip {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        match self.position {
+            StripPosition::Everywhere => data.retain(|c| !self.matches(c)),
+            StripPosition::Start => self.strip_start(data),
+            StripPosition::End => self.strip_end(data),
+            StripPosition::Both => {
+                self.strip_end(data);
+                self.strip_start(data);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_strip_nothing() {
+        let mut data = String::from("lorem ipsum");
+
+        Strip::new().clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum");
+    }
+
+    #[test]
+    fn test_strip_chars() {
+        let mut data = String::from("l'œuvre, «lorem» ipsum");
+
+        Strip::new().chars("'«»,".chars()).clean(&mut data);
+
+        assert_eq!(data, "lœuvre lorem ipsum");
+    }
+
+    #[test]
+    fn test_strip_range() {
+        let mut data = String::from("page 12 / 345");
+
+        Strip::new().range('0'..='9').clean(&mut data);
+
+        assert_eq!(data, "page  / ");
+    }
+
+    #[test]
+    fn test_strip_category() {
+        let mut data = String::from("cafe\u{301} ☕ ♥ ok");
+
+        Strip::new()
+            .category(GeneralCategory::NonspacingMark)
+            .category(GeneralCategory::OtherSymbol)
+            .clean(&mut data);
+
+        assert_eq!(data, "cafe   ok");
+    }
+
+    #[test]
+    fn test_strip_predicate() {
+        let mut data = String::from("Lorem Ipsum");
+
+        Strip::new()
+            .predicate(|c| c.is_uppercase())
+            .clean(&mut data);
+
+        assert_eq!(data, "orem psum");
+    }
+
+    #[test]
+    fn test_strip_position() {
+        let strip = Strip::new().chars(['.', '…']);
+
+        let mut data = String::from("…lorem. ipsum.…");
+        strip
+            .clone()
+            .position(StripPosition::Start)
+            .clean(&mut data);
+        assert_eq!(data, "lorem. ipsum.…");
+
+        let mut data = String::from("…lorem. ipsum.…");
+        strip.clone().position(StripPosition::End).clean(&mut data);
+        assert_eq!(data, "…lorem. ipsum");
+
+        let mut data = String::from("…lorem. ipsum.…");
+        strip.clone().position(StripPosition::Both).clean(&mut data);
+        assert_eq!(data, "lorem. ipsum");
+
+        let mut data = String::from("….…");
+        strip.position(StripPosition::Both).clean(&mut data);
+        assert_eq!(data, "");
+    }
+
+    #[test]
+    fn test_general_category_from_abbreviation() {
+        assert_eq!(
+            general_category_from_abbreviation("Mn"),
+            Some(GeneralCategory::NonspacingMark)
+        );
+        assert_eq!(
+            general_category_from_abbreviation("So"),
+            Some(GeneralCategory::OtherSymbol)
+        );
+        assert_eq!(general_category_from_abbreviation("Xx"), None);
+    }
+}
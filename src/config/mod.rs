@@ -0,0 +1,1016 @@
+use std::convert::TryFrom;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use crate::ascii::{AsciiFallback, AsciiTransliterator};
+use crate::case::{Case, CaseLocale, CaseNormalizer};
+use crate::common::{
+    general_category_from_abbreviation, AppendAfterChar, AppendAfterCharIfDifferent,
+    ControlCharRemover, GeneralCategory, Occurrences, PrependBeforeChar,
+    PrependBeforeCharIfDifferent, SpaceAround, Spacing, Strip, StripPosition,
+};
+use crate::english::{EnglishQuotes, EnglishTypography};
+use crate::french::{
+    FrenchRuleGroup, FrenchSpaceRemover, FrenchSubstitutions, FrenchSubstitutionsBuilder,
+    FrenchTypography, FrenchTypographyMode,
+};
+use crate::german::{GermanQuotes, GermanSubstitutions, GermanTypography, SharpS};
+use crate::languages::{language_pack, LanguagePack};
+use crate::pipeline::{Pipeline, PipelineBuilder};
+use crate::substitutions::{RegexError, RegexSubstitute, Substitute, SubstitutionsTable};
+use crate::unicode::{
+    CompatibilityCategory, CompatibilityFolding, DiacriticRemover, NfcNormalizer, NfdNormalizer,
+    NfkcNormalizer, NfkdNormalizer, ScriptNotation, SuperscriptConverter, UnicodeScope,
+};
+use crate::whitespaces::{
+    ConsecutiveWhiteSpaceRemover, SpaceNormalizer, SpaceTrimmer, WhitespaceNormalizer,
+};
+
+/// Error raised while loading a pipeline configuration
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Toml(toml::de::Error),
+    Yaml(serde_yaml::Error),
+    Json(serde_json::Error),
+    /// The format of the file can't be guessed from its extension
+    UnknownFormat(PathBuf),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(e) => write!(f, "{}", e),
+            ConfigError::Toml(e) => write!(f, "invalid TOML configuration: {}", e),
+            ConfigError::Yaml(e) => write!(f, "invalid YAML configuration: {}", e),
+            ConfigError::Json(e) => write!(f, "invalid JSON configuration: {}", e),
+            ConfigError::UnknownFormat(path) => write!(
+                f,
+                "unknown configuration format for `{}` (expected .toml, .yaml, .yml or .json)",
+                path.display()
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ConfigError::Io(e) => Some(e),
+            ConfigError::Toml(e) => Some(e),
+            ConfigError::Yaml(e) => Some(e),
+            ConfigError::Json(e) => Some(e),
+            ConfigError::UnknownFormat(_) => None,
+        }
+    }
+}
+
+impl From<io::Error> for ConfigError {
+    fn from(e: io::Error) -> Self {
+        ConfigError::Io(e)
+    }
+}
+
+/// A unicode general category of a pipeline configuration, written as its abbreviation (`Mn`, `So`, …)
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "String")]
+pub struct GeneralCategoryConfig(pub GeneralCategory);
+
+impl TryFrom<String> for GeneralCategoryConfig {
+    type Error = String;
+
+    fn try_from(abbreviation: String) -> Result<Self, Self::Error> {
+        general_category_from_abbreviation(&abbreviation)
+            .map(GeneralCategoryConfig)
+            .ok_or_else(|| format!("unknown unicode general category `{}`", abbreviation))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawUnicodeScopeConfig {
+    block: Option<String>,
+    script: Option<String>,
+    #[serde(default)]
+    outside: bool,
+}
+
+/// A unicode scope of a pipeline configuration, either `{ block = "…" }` or `{ script = "…" }`,
+/// inverted with `outside = true`
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq)]
+#[serde(try_from = "RawUnicodeScopeConfig")]
+pub struct UnicodeScopeConfig(pub UnicodeScope);
+
+impl TryFrom<RawUnicodeScopeConfig> for UnicodeScopeConfig {
+    type Error = String;
+
+    fn try_from(raw: RawUnicodeScopeConfig) -> Result<Self, Self::Error> {
+        let scope = match (raw.block, raw.script) {
+            (Some(block), None) => UnicodeScope::block(&block),
+            (None, Some(script)) => UnicodeScope::script(&script),
+            _ => return Err("a unicode scope needs either a `block` or a `script`".to_string()),
+        }
+        .map_err(|e| e.to_string())?;
+
+        Ok(UnicodeScopeConfig(if raw.outside {
+            scope.outside()
+        } else {
+            scope
+        }))
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RawRegexSubstituteConfig {
+    pattern: String,
+    replacement: String,
+}
+
+/// A regex substitution of a pipeline configuration, the regex is compiled when the
+/// configuration is loaded
+#[derive(Clone, Debug, Deserialize)]
+#[serde(try_from = "RawRegexSubstituteConfig")]
+pub struct RegexSubstituteConfig(pub RegexSubstitute);
+
+impl TryFrom<RawRegexSubstituteConfig> for RegexSubstituteConfig {
+    type Error = RegexError;
+
+    fn try_from(raw: RawRegexSubstituteConfig) -> Result<Self, Self::Error> {
+        RegexSubstitute::new(&raw.pattern, &raw.replacement).map(RegexSubstituteConfig)
+    }
+}
+
+impl PartialEq for RegexSubstituteConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.regex().as_str() == other.0.regex().as_str()
+            && self.0.replacement() == other.0.replacement()
+    }
+}
+
+/// A language pack of a pipeline configuration, given by its code (`fr`, `de`, …)
+#[derive(Clone, Copy, Deserialize)]
+#[serde(try_from = "String")]
+pub struct LanguageConfig(pub &'static dyn LanguagePack);
+
+impl TryFrom<String> for LanguageConfig {
+    type Error = String;
+
+    fn try_from(code: String) -> Result<Self, Self::Error> {
+        language_pack(&code)
+            .map(LanguageConfig)
+            .ok_or_else(|| format!("unknown language `{}`", code))
+    }
+}
+
+impl fmt::Debug for LanguageConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LanguageConfig")
+            .field(&self.0.code())
+            .finish()
+    }
+}
+
+impl PartialEq for LanguageConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.code() == other.0.code()
+    }
+}
+
+/// A spacing rule of a pipeline configuration, see [`SpaceAround`]
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SpacingRuleConfig {
+    pub chars: String,
+    #[serde(default)]
+    pub before: Spacing,
+    #[serde(default)]
+    pub after: Spacing,
+}
+
+/// A step of a pipeline configuration
+#[derive(Clone, Debug, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum StepConfig {
+    NfcNormalizer,
+    NfdNormalizer,
+    NfkcNormalizer,
+    NfkdNormalizer,
+    Substitutions {
+        lang: LanguageConfig,
+    },
+    Typography {
+        lang: LanguageConfig,
+    },
+    FrenchSubstitutions {
+        #[serde(default)]
+        scope: Option<UnicodeScopeConfig>,
+        /// Groups of substitutions, those of [`FrenchSubstitutions::new`] if missing
+        #[serde(default)]
+        groups: Option<Vec<FrenchRuleGroup>>,
+        #[serde(default)]
+        substitutions: SubstitutionsTable,
+    },
+    FrenchTypography {
+        #[serde(default)]
+        no_break_spaces: bool,
+        #[serde(default)]
+        mode: FrenchTypographyMode,
+    },
+    FrenchSpaceRemover {
+        #[serde(default)]
+        straight_quotes: bool,
+    },
+    EnglishTypography {
+        #[serde(default)]
+        quotes: EnglishQuotes,
+        #[serde(default)]
+        punctuation_spacing: bool,
+    },
+    GermanSubstitutions {
+        #[serde(default)]
+        sharp_s: SharpS,
+        #[serde(default)]
+        transliterate_umlauts: bool,
+        #[serde(default)]
+        quotes: GermanQuotes,
+    },
+    GermanTypography,
+    ControlCharRemover,
+    ConsecutiveWhiteSpaceRemover,
+    SpaceNormalizer,
+    SpaceTrimmer,
+    WhitespaceNormalizer,
+    AppendAfterChar {
+        target: char,
+        insert: char,
+        #[serde(default)]
+        occurrences: Occurrences,
+    },
+    AppendAfterCharIfDifferent {
+        target: char,
+        insert: char,
+        #[serde(default)]
+        occurrences: Occurrences,
+    },
+    PrependBeforeChar {
+        target: char,
+        insert: char,
+        #[serde(default)]
+        occurrences: Occurrences,
+    },
+    PrependBeforeCharIfDifferent {
+        target: char,
+        insert: char,
+        #[serde(default)]
+        occurrences: Occurrences,
+    },
+    Substitute {
+        substitutions: SubstitutionsTable,
+        #[serde(default)]
+        scope: Option<UnicodeScopeConfig>,
+    },
+    RegexSubstitute(RegexSubstituteConfig),
+    Strip {
+        #[serde(default)]
+        chars: String,
+        #[serde(default)]
+        ranges: Vec<(char, char)>,
+        #[serde(default)]
+        categories: Vec<GeneralCategoryConfig>,
+        #[serde(default)]
+        position: StripPosition,
+    },
+    SpaceAround {
+        rules: Vec<SpacingRuleConfig>,
+    },
+    AsciiTransliterator {
+        #[serde(default)]
+        fallback: AsciiFallback,
+    },
+    CompatibilityFolding {
+        categories: Vec<CompatibilityCategory>,
+    },
+    SuperscriptConverter {
+        #[serde(default)]
+        notation: ScriptNotation,
+    },
+    DiacriticRemover {
+        #[serde(default)]
+        keep: String,
+    },
+    CaseNormalizer {
+        case: Case,
+        #[serde(default)]
+        locale: CaseLocale,
+    },
+}
+
+impl StepConfig {
+    fn add_to(&self, builder: PipelineBuilder) -> PipelineBuilder {
+        match self {
+            StepConfig::NfcNormalizer => builder.normalize(NfcNormalizer),
+            StepConfig::NfdNormalizer => builder.normalize(NfdNormalizer),
+            StepConfig::NfkcNormalizer => builder.normalize(NfkcNormalizer),
+            StepConfig::NfkdNormalizer => builder.normalize(NfkdNormalizer),
+            StepConfig::Substitutions { lang } => builder.pipeline(&lang.0.substitutions()),
+            StepConfig::Typography { lang } => builder.pipeline(&lang.0.typography()),
+            StepConfig::FrenchSubstitutions {
+                scope,
+                groups,
+                substitutions,
+            } => {
+                let substitutions = match groups {
+                    Some(groups) => groups
+                        .iter()
+                        .fold(FrenchSubstitutionsBuilder::new(), |builder, group| {
+                            builder.with(*group)
+                        }),
+                    None => FrenchSubstitutions::builder(),
+                }
+                .substitutions(substitutions)
+                .build();
+
+                builder.clean(match scope {
+                    Some(scope) => substitutions.unicode_block_scope(scope.0),
+                    None => substitutions,
+                })
+            }
+            StepConfig::FrenchTypography {
+                no_break_spaces,
+                mode,
+            } => builder.normalize(
+                FrenchTypography::new()
+                    .no_break_spaces(*no_break_spaces)
+                    .mode(*mode),
+            ),
+            StepConfig::FrenchSpaceRemover { straight_quotes } => {
+                builder.normalize(FrenchSpaceRemover::new().straight_quotes(*straight_quotes))
+            }
+            StepConfig::EnglishTypography {
+                quotes,
+                punctuation_spacing,
+            } => builder.normalize(
+                EnglishTypography::new()
+                    .quotes(*quotes)
+                    .punctuation_spacing(*punctuation_spacing),
+            ),
+            StepConfig::GermanSubstitutions {
+                sharp_s,
+                transliterate_umlauts,
+                quotes,
+            } => builder.clean(
+                GermanSubstitutions::new()
+                    .sharp_s(*sharp_s)
+                    .transliterate_umlauts(*transliterate_umlauts)
+                    .quotes(*quotes),
+            ),
+            StepConfig::GermanTypography => builder.normalize(GermanTypography::new()),
+            StepConfig::ControlCharRemover => builder.clean(ControlCharRemover),
+            StepConfig::ConsecutiveWhiteSpaceRemover => builder.clean(ConsecutiveWhiteSpaceRemover),
+            StepConfig::SpaceNormalizer => builder.normalize(SpaceNormalizer),
+            StepConfig::SpaceTrimmer => builder.clean(SpaceTrimmer),
+            StepConfig::WhitespaceNormalizer => builder.clean(WhitespaceNormalizer),
+            StepConfig::AppendAfterChar {
+                target,
+                insert,
+                occurrences,
+            } => {
+                builder.normalize(AppendAfterChar::new(*target, *insert).occurrences(*occurrences))
+            }
+            StepConfig::AppendAfterCharIfDifferent {
+                target,
+                insert,
+                occurrences,
+            } => builder.normalize(
+                AppendAfterCharIfDifferent::new(*target, *insert).occurrences(*occurrences),
+            ),
+            StepConfig::PrependBeforeChar {
+                target,
+                insert,
+                occurrences,
+            } => builder
+                .normalize(PrependBeforeChar::new(*target, *insert).occurrences(*occurrences)),
+            StepConfig::PrependBeforeCharIfDifferent {
+                target,
+                insert,
+                occurrences,
+            } => builder.normalize(
+                PrependBeforeCharIfDifferent::new(*target, *insert).occurrences(*occurrences),
+            ),
+            StepConfig::Substitute {
+                substitutions,
+                scope,
+            } => {
+                let substitute = Substitute::new(substitutions);
+
+                builder.clean(match scope {
+                    Some(scope) => substitute.unicode_block_scope(scope.0),
+                    None => substitute,
+                })
+            }
+            StepConfig::RegexSubstitute(regex_substitute) => {
+                builder.clean(regex_substitute.0.clone())
+            }
+            StepConfig::Strip {
+                chars,
+                ranges,
+                categories,
+                position,
+            } => {
+                let strip = ranges
+                    .iter()
+                    .fold(Strip::new().chars(chars.chars()), |strip, (start, end)| {
+                        strip.range(*start..=*end)
+                    });
+
+                let strip = categories
+                    .iter()
+                    .fold(strip, |strip, category| strip.category(category.0));
+
+                builder.clean(strip.position(*position))
+            }
+            StepConfig::AsciiTransliterator { fallback } => {
+                builder.clean(AsciiTransliterator::new().fallback(fallback.clone()))
+            }
+            StepConfig::CaseNormalizer { case, locale } => {
+                builder.normalize(CaseNormalizer::new(*case).locale(*locale))
+            }
+            StepConfig::DiacriticRemover { keep } => {
+                builder.normalize(DiacriticRemover::new().keep(keep.chars()))
+            }
+            StepConfig::SuperscriptConverter { notation } => {
+                builder.clean(SuperscriptConverter::new().notation(*notation))
+            }
+            StepConfig::CompatibilityFolding { categories } => builder.clean(
+                categories
+                    .iter()
+                    .fold(CompatibilityFolding::new(), |folding, category| {
+                        folding.with(*category)
+                    }),
+            ),
+            StepConfig::SpaceAround { rules } => {
+                builder.normalize(rules.iter().fold(SpaceAround::new(), |space_around, rule| {
+                    space_around.rule(rule.chars.chars(), rule.before, rule.after)
+                }))
+            }
+        }
+    }
+}
+
+/// A declarative description of a [`Pipeline`].
+///
+/// ```
+/// use textual_data_cleaner::config::PipelineConfig;
+/// use textual_data_cleaner::Clean;
+///
+/// let config = PipelineConfig::from_toml_str(
+///     r#"
+///     [[steps]]
+///     type = "substitute"
+///     substitutions = [
+///         { char = "œ", replacement = "oe" },
+///         { range = ["0", "9"], replacement = "_" },
+///         { pattern = "&nbsp;", replacement = " " },
+///     ]
+///
+///     [[steps]]
+///     type = "append-after-char-if-different"
+///     target = ";"
+///     insert = " "
+///     "#,
+/// )
+/// .unwrap();
+///
+/// let mut data = String::from("œuvre;42&nbsp;");
+/// config.build().clean(&mut data);
+///
+/// assert_eq!(data, "oeuvre; __ ");
+/// ```
+#[derive(Clone, Debug, Default, Deserialize, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PipelineConfig {
+    pub steps: Vec<StepConfig>,
+}
+
+impl PipelineConfig {
+    pub fn from_toml_str(config: &str) -> Result<Self, ConfigError> {
+        toml::from_str(config).map_err(ConfigError::Toml)
+    }
+
+    pub fn from_yaml_str(config: &str) -> Result<Self, ConfigError> {
+        serde_yaml::from_str(config).map_err(ConfigError::Yaml)
+    }
+
+    pub fn from_json_str(config: &str) -> Result<Self, ConfigError> {
+        serde_json::from_str(config).map_err(ConfigError::Json)
+    }
+
+    /// Loads a configuration file, its format is guessed from its extension
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        let parse = match extension {
+            Some("toml") => Self::from_toml_str,
+            Some("yaml") | Some("yml") => Self::from_yaml_str,
+            Some("json") => Self::from_json_str,
+            _ => return Err(ConfigError::UnknownFormat(path.to_path_buf())),
+        };
+
+        parse(&fs::read_to_string(path)?)
+    }
+
+    /// Builds the pipeline described by the configuration
+    pub fn build(&self) -> Pipeline {
+        self.steps
+            .iter()
+            .fold(Pipeline::builder(), |builder, step| step.add_to(builder))
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clean::Clean;
+    use crate::substitutions::Substitution;
+
+    fn expected_config() -> PipelineConfig {
+        PipelineConfig {
+            steps: vec![
+                StepConfig::FrenchSubstitutions {
+                    scope: None,
+                    groups: None,
+                    substitutions: SubstitutionsTable::new(),
+                },
+                StepConfig::PrependBeforeCharIfDifferent {
+                    target: ':',
+                    insert: ' ',
+                    occurrences: Occurrences::All,
+                },
+                StepConfig::Substitute {
+                    substitutions: vec![
+                        Substitution::char('…', "..."),
+                        Substitution::range('0'..='9', ""),
+                    ]
+                    .into(),
+                    scope: None,
+                },
+                StepConfig::WhitespaceNormalizer,
+            ],
+        }
+    }
+
+    #[test]
+    fn test_toml_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "french-substitutions"
+
+            [[steps]]
+            type = "prepend-before-char-if-different"
+            target = ":"
+            insert = " "
+
+            [[steps]]
+            type = "substitute"
+            substitutions = [
+                { char = "…", replacement = "..." },
+                { range = ["0", "9"], replacement = "" },
+            ]
+
+            [[steps]]
+            type = "whitespace-normalizer"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(config, expected_config());
+    }
+
+    #[test]
+    fn test_yaml_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+steps:
+  - type: french-substitutions
+  - type: prepend-before-char-if-different
+    target: ":"
+    insert: " "
+  - type: substitute
+    substitutions:
+      - char: "…"
+        replacement: "..."
+      - range: ["0", "9"]
+        replacement: ""
+  - type: whitespace-normalizer
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(config, expected_config());
+    }
+
+    #[test]
+    fn test_json_config() {
+        let config = PipelineConfig::from_json_str(
+            r#"{
+                "steps": [
+                    { "type": "french-substitutions" },
+                    { "type": "prepend-before-char-if-different", "target": ":", "insert": " " },
+                    {
+                        "type": "substitute",
+                        "substitutions": [
+                            { "char": "…", "replacement": "..." },
+                            { "range": ["0", "9"], "replacement": "" }
+                        ]
+                    },
+                    { "type": "whitespace-normalizer" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config, expected_config());
+    }
+
+    #[test]
+    fn test_configured_pipeline() {
+        let mut data = String::from(" « Œuvre: 2022… » ");
+
+        expected_config().build().clean(&mut data);
+
+        assert_eq!(data, "\" OEuvre : ... \"");
+    }
+
+    #[test]
+    fn test_strip_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+steps:
+  - type: strip
+    chars: "-"
+    position: both
+  - type: strip
+    ranges: [["0", "9"]]
+    categories: [Mn]
+"#,
+        )
+        .unwrap();
+
+        let mut data = String::from("--e\u{301}te\u{301} 2022--");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "ete ");
+
+        assert!(matches!(
+            PipelineConfig::from_json_str(
+                r#"{"steps": [{"type": "strip", "categories": ["Xx"]}]}"#
+            ),
+            Err(ConfigError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_scope_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "substitute"
+            substitutions = [
+                { char = "ﬁ", replacement = "fi" },
+                { char = "ж", replacement = "zh" },
+            ]
+            scope = { block = "Cyrillic", outside = true }
+
+            [[steps]]
+            type = "substitute"
+            substitutions = [{ char = "ж", replacement = "zh" }]
+            scope = { script = "Latin" }
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("ﬁn ж");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "fin ж");
+
+        assert!(matches!(
+            PipelineConfig::from_toml_str(
+                "[[steps]]\ntype = \"french-substitutions\"\nscope = { block = \"Klingon\" }"
+            ),
+            Err(ConfigError::Toml(_))
+        ));
+    }
+
+    #[test]
+    fn test_regex_substitute_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+steps:
+  - type: regex-substitute
+    pattern: "- \\d+ -"
+    replacement: ""
+  - type: regex-substitute
+    pattern: "([!?])+"
+    replacement: "$1"
+  - type: whitespace-normalizer
+"#,
+        )
+        .unwrap();
+
+        let mut data = String::from("lorem - 12 - ipsum !!!");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum !");
+
+        assert!(matches!(
+            PipelineConfig::from_json_str(
+                r#"{"steps": [{"type": "regex-substitute", "pattern": "(", "replacement": ""}]}"#
+            ),
+            Err(ConfigError::Json(_))
+        ));
+    }
+
+    #[test]
+    fn test_occurrences_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "append-after-char"
+            target = ";"
+            insert = " "
+            occurrences = "first"
+
+            [[steps]]
+            type = "prepend-before-char-if-different"
+            target = ";"
+            insert = "_"
+            occurrences = { nth = 2 }
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("a;b;c;d");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "a; b;c_;d");
+    }
+
+    #[test]
+    fn test_space_around_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: space-around
+                rules:
+                  - chars: ";!?"
+                    before: narrow-no-break-space
+                    after: space
+                  - chars: "("
+                    after: none
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("oui !non ( lorem) ;ipsum");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "oui\u{202f}! non (lorem)\u{202f}; ipsum");
+    }
+
+    #[test]
+    fn test_french_typography_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "french-typography"
+            no_break_spaces = true
+            mode = "convert"
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("« oui ! »");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "«\u{a0}oui\u{202f}!\u{a0}»");
+
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "french-space-remover"
+            straight_quotes = true
+            "#,
+        )
+        .unwrap();
+
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "\"oui!\"");
+    }
+
+    #[test]
+    fn test_language_config() {
+        let config = PipelineConfig::from_json_str(
+            r#"{
+                "steps": [
+                    { "type": "substitutions", "lang": "es" },
+                    { "type": "typography", "lang": "es" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let mut data = String::from("¿ Qué pasa ? ’«Nada»’");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "¿Qué pasa? '\"Nada\"'");
+
+        assert!(PipelineConfig::from_json_str(
+            r#"{ "steps": [{ "type": "typography", "lang": "xx" }] }"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn test_german_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: german-typography
+              - type: german-substitutions
+                sharp_s: expand
+                quotes: straight
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("»Grüße ! «");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "\"Grüsse!\"");
+    }
+
+    #[test]
+    fn test_english_typography_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "english-typography"
+            quotes = "curly"
+            punctuation_spacing = true
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("\"It's 'fine' !\"");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "“It’s ‘fine’!”");
+    }
+
+    #[test]
+    fn test_french_substitutions_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "french-substitutions"
+            groups = ["quotes", "apostrophes", "ellipsis"]
+            substitutions = [{ char = "ſ", replacement = "s" }]
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("«Œuvre» de l’auteur… ſans");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "\"Œuvre\" de l'auteur... sans");
+    }
+
+    #[test]
+    fn test_ascii_transliterator_config() {
+        let config = PipelineConfig::from_toml_str(
+            r#"
+            [[steps]]
+            type = "ascii-transliterator"
+            fallback = { replace = "_" }
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("Café 東京");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "Cafe __");
+    }
+
+    #[test]
+    fn test_compatibility_folding_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: compatibility-folding
+                categories: [ligatures, width]
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("ﬁn Ｈ₂Ｏ");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "fin H₂O");
+    }
+
+    #[test]
+    fn test_superscript_converter_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: superscript-converter
+                notation: caret
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("1ᵉʳ, XIXᵉ");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "1^er, XIX^e");
+    }
+
+    #[test]
+    fn test_diacritic_remover_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: diacritic-remover
+                keep: çÇ
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("Ça a été reçu");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "Ça a ete reçu");
+    }
+
+    #[test]
+    fn test_case_normalizer_config() {
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: case-normalizer
+                case: fold
+              - type: whitespace-normalizer
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("  GROẞE   Straße ");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "grosse strasse");
+
+        let config = PipelineConfig::from_yaml_str(
+            r#"
+            steps:
+              - type: case-normalizer
+                case: upper
+                locale: turkish
+            "#,
+        )
+        .unwrap();
+
+        let mut data = String::from("istanbul");
+        config.build().clean(&mut data);
+
+        assert_eq!(data, "İSTANBUL");
+    }
+
+    #[test]
+    fn test_invalid_config() {
+        assert!(matches!(
+            PipelineConfig::from_toml_str("[[steps]]\ntype = \"unknown-step\""),
+            Err(ConfigError::Toml(_))
+        ));
+
+        assert!(matches!(
+            PipelineConfig::from_json_str(r#"{"steps": [{"type": "append-after-char"}]}"#),
+            Err(ConfigError::Json(_))
+        ));
+
+        assert!(matches!(
+            PipelineConfig::from_path("pipeline.ini"),
+            Err(ConfigError::UnknownFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,261 @@
+use serde::Deserialize;
+
+use crate::common::{SpaceAround, Spacing};
+use crate::normalize::Normalize;
+
+/// Words starting with an elided letter, such as `'tis` or `'n'`
+const LEADING_ELISIONS: [&str; 9] = [
+    "tis", "twas", "til", "em", "n", "cause", "bout", "round", "ere",
+];
+
+/// Style of the English quotes and apostrophes
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum EnglishQuotes {
+    /// Keeps the quotes as they are
+    #[default]
+    Keep,
+    /// Converts the straight quotes to curly ones: `“…”`, `‘…’` and `’` for the apostrophes
+    Curly,
+    /// Converts the curly quotes to straight ones: `"…"` and `'…'`
+    Straight,
+}
+
+/// Returns true if a quote following this char opens a quotation
+fn is_opening_context(previous_char: Option<char>) -> bool {
+    match previous_char {
+        None => true,
+        Some(c) => c.is_whitespace() || "([{<—–-‘“/".contains(c),
+    }
+}
+
+/// Returns true if the text starts with a decade (`90s`, `80`) followed by a word boundary
+fn starts_with_decade(text: &str) -> bool {
+    let mut chars = text.chars();
+
+    for _ in 0..2 {
+        if !chars.next().is_some_and(|c| c.is_ascii_digit()) {
+            return false;
+        }
+    }
+
+    match chars.next() {
+        Some('s') => !chars.next().is_some_and(char::is_alphanumeric),
+        Some(c) => !c.is_alphanumeric(),
+        None => true,
+    }
+}
+
+/// Returns true if the text starts with a word whose first letter was elided, such as `90s`
+fn starts_with_elided_word(text: &str) -> bool {
+    if starts_with_decade(text) {
+        return true;
+    }
+
+    let word_end = text
+        .find(|c: char| !c.is_alphabetic())
+        .unwrap_or(text.len());
+
+    let word = text[..word_end].to_lowercase();
+
+    LEADING_ELISIONS.contains(&word.as_str())
+}
+
+/// Converts the straight quotes and apostrophes of a String to curly ones
+fn curl_quotes(data: &mut String) {
+    if !data.contains(['\'', '"']) {
+        return;
+    }
+
+    let mut output = String::with_capacity(data.len() + 8);
+    let mut previous_char: Option<char> = None;
+    let mut previous_curly: Option<char> = None;
+
+    for (i, c) in data.char_indices() {
+        let next_text = &data[i + c.len_utf8()..];
+        let next_char = next_text.chars().next();
+        // A quote right after an opening quote of the other kind is nested in it (`“‘`), while
+        // a quote of the same kind closes an empty quotation (`“”`)
+        let opening = is_opening_context(previous_char)
+            || (matches!(previous_curly, Some('‘' | '“')) && previous_char != Some(c));
+
+        let curly = match c {
+            '"' if opening => '“',
+            '"' => '”',
+            '\'' if previous_char.is_some_and(char::is_alphanumeric) => '’',
+            '\'' if opening && starts_with_elided_word(next_text) => '’',
+            '\'' if opening && next_char.is_some() => '‘',
+            '\'' => '’',
+            c => c,
+        };
+
+        output.push(curly);
+        previous_char = Some(c);
+        previous_curly = Some(curly);
+    }
+
+    *data = output;
+}
+
+/// Converts the curly quotes and apostrophes of a String to straight ones
+fn straighten_quotes(data: &mut String) {
+    if !data.contains(['‘', '’', '‛', '“', '”', '‟']) {
+        return;
+    }
+
+    *data = data
+        .chars()
+        .map(|c| match c {
+            '‘' | '’' | '‛' => '\'',
+            '“' | '”' | '‟' => '"',
+            c => c,
+        })
+        .collect();
+}
+
+/// Normalize for the English language
+///
+/// Converts the quotes and, optionally, removes the spaces before the punctuation. When curling
+/// quotes, the apostrophes of contractions (`don’t`) and leading elisions (`’90s`, `’tis`) are
+/// told apart from the opening quotes.
+///
+/// ```
+/// use textual_data_cleaner::english::{EnglishQuotes, EnglishTypography};
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("\"Don't say 'rock 'n' roll' ,\" he said in the '90s.");
+/// EnglishTypography::new()
+///     .quotes(EnglishQuotes::Curly)
+///     .punctuation_spacing(true)
+///     .normalize(&mut data);
+///
+/// assert_eq!(data, "“Don’t say ‘rock ’n’ roll’,” he said in the ’90s.");
+///
+/// EnglishTypography::new()
+///     .quotes(EnglishQuotes::Straight)
+///     .normalize(&mut data);
+///
+/// assert_eq!(data, "\"Don't say 'rock 'n' roll',\" he said in the '90s.");
+/// ```
+#[derive(Clone)]
+pub struct EnglishTypography {
+    quotes: EnglishQuotes,
+    punctuation_spacing: bool,
+    space_around: SpaceAround,
+}
+
+impl EnglishTypography {
+    pub fn new() -> EnglishTypography {
+        EnglishTypography {
+            quotes: EnglishQuotes::Keep,
+            punctuation_spacing: false,
+            space_around: SpaceAround::new().before([',', '.', ':', ';', '!', '?'], Spacing::None),
+        }
+    }
+
+    pub fn quotes(mut self, quotes: EnglishQuotes) -> Self {
+        self.quotes = quotes;
+        self
+    }
+
+    /// Removes the spaces before `,`, `.`, `:`, `;`, `!` and `?`
+    pub fn punctuation_spacing(mut self, punctuation_spacing: bool) -> Self {
+        self.punctuation_spacing = punctuation_spacing;
+        self
+    }
+}
+
+impl Default for EnglishTypography {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Normalize for EnglishTypography {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if self.punctuation_spacing {
+            self.space_around.normalize(data);
+        }
+
+        match self.quotes {
+            EnglishQuotes::Keep => {}
+            EnglishQuotes::Curly => curl_quotes(data),
+            EnglishQuotes::Straight => straighten_quotes(data),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curl(input: &str) -> String {
+        let mut data = input.to_string();
+        EnglishTypography::new()
+            .quotes(EnglishQuotes::Curly)
+            .normalize(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_curly_quotes() {
+        assert_eq!(curl("\"Hello\" (\"world\")"), "“Hello” (“world”)");
+        assert_eq!(curl("'single' and \"'nested'\""), "‘single’ and “‘nested’”");
+        assert_eq!(curl("\"Stop!\"\n\"Go\""), "“Stop!”\n“Go”");
+    }
+
+    #[test]
+    fn test_curly_empty_quotes() {
+        assert_eq!(curl("\"\""), "“”");
+        assert_eq!(curl("''"), "‘’");
+        assert_eq!(
+            curl("say \"\" or '' then \"'x'\""),
+            "say “” or ‘’ then “‘x’”"
+        );
+    }
+
+    #[test]
+    fn test_curly_apostrophes() {
+        assert_eq!(curl("don't, it's, rock'n'roll"), "don’t, it’s, rock’n’roll");
+        assert_eq!(curl("the students' books"), "the students’ books");
+        assert_eq!(curl("'Tis the '80s, 'em all"), "’Tis the ’80s, ’em all");
+        assert_eq!(curl("a 'tisane'"), "a ‘tisane’");
+    }
+
+    #[test]
+    fn test_curly_decades() {
+        assert_eq!(curl("the '90s and '80s"), "the ’90s and ’80s");
+        assert_eq!(curl("summer of '69."), "summer of ’69.");
+        assert_eq!(curl("'1984' and '9'"), "‘1984’ and ‘9’");
+        assert_eq!(curl("'90sx'"), "‘90sx’");
+    }
+
+    #[test]
+    fn test_straight_quotes() {
+        let mut data = "“Don’t”, ‘she’ said ‟ok‛".to_string();
+
+        EnglishTypography::new()
+            .quotes(EnglishQuotes::Straight)
+            .normalize(&mut data);
+
+        assert_eq!(data, "\"Don't\", 'she' said \"ok'");
+    }
+
+    #[test]
+    fn test_quotes_keep_spacing() {
+        assert_eq!(curl("\"Hi\" , 'you' !"), "“Hi” , ‘you’ !");
+    }
+
+    #[test]
+    fn test_punctuation_spacing() {
+        let mut data = "\"Hi\" , ‘you’ !".to_string();
+
+        EnglishTypography::new()
+            .punctuation_spacing(true)
+            .normalize(&mut data);
+
+        assert_eq!(data, "\"Hi\", ‘you’!");
+    }
+}
@@ -1,50 +1,173 @@
-use std::ops::RangeInclusive;
+use serde::Deserialize;
 
 use crate::clean::Clean;
 use crate::common::{
-    AppendAfterCharIf, AppendAfterCharIfDifferent, PrependBeforeCharIf,
-    PrependBeforeCharIfDifferent,
+    AppendAfterCharIf, InsertionContext, PrependBeforeCharIf, SpaceAround, Spacing,
 };
 use crate::normalize::Normalize;
-use crate::substitutions::{substitutions, Substitute, SubstitutionsList};
+use crate::substitutions;
+use crate::substitutions::{Substitute, Substitution, SubstitutionsList, SubstitutionsTable};
+use crate::unicode::UnicodeScope;
 use crate::whitespaces::SpaceTrimmer;
 
+/// A group of substitutions of [`FrenchSubstitutions`]
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "kebab-case")]
+pub enum FrenchRuleGroup {
+    /// Expands `œ`, `Œ`, `æ` and `Æ`
+    Ligatures,
+    /// Replaces `“`, `”`, `«` and `»` with `"`
+    Quotes,
+    /// Replaces `` ` ``, `‘` and `’` with `'`
+    Apostrophes,
+    /// Replaces the dashes (`–`, `—`, etc.) with `-`
+    Dashes,
+    /// Replaces `…` with `...`
+    Ellipsis,
+}
+
+impl FrenchRuleGroup {
+    fn substitutions(&self) -> SubstitutionsList {
+        match self {
+            FrenchRuleGroup::Ligatures => substitutions! {
+                'œ' => "oe",
+                'Œ' => "OE",
+                'æ' => "ae",
+                'Æ' => "AE",
+            },
+            FrenchRuleGroup::Quotes => substitutions! {
+                '“', '”', '«', '»' => "\"",
+            },
+            FrenchRuleGroup::Apostrophes => substitutions! {
+                '`', '‘', '’' => "'",
+            },
+            FrenchRuleGroup::Dashes => substitutions! {
+                '\u{2010}'..='\u{2015}' => "-",
+            },
+            FrenchRuleGroup::Ellipsis => substitutions! {
+                '…' => "...",
+            },
+        }
+    }
+}
+
+/// Builds [`FrenchSubstitutions`] from groups of substitutions and custom substitutions
+///
+/// The custom substitutions have precedence over the groups.
+///
+/// ```
+/// use textual_data_cleaner::french::{FrenchRuleGroup, FrenchSubstitutions};
+/// use textual_data_cleaner::substitutions::Substitution;
+/// use textual_data_cleaner::Clean;
+///
+/// let substitutions = FrenchSubstitutions::builder()
+///     .without(FrenchRuleGroup::Ligatures)
+///     .with(FrenchRuleGroup::Ellipsis)
+///     .substitution(Substitution::char('ſ', "s"))
+///     .build();
+///
+/// let mut data = String::from("«Œuvre» de l’auteur… ſans");
+/// substitutions.clean(&mut data);
+///
+/// assert_eq!(data, "\"Œuvre\" de l'auteur... sans");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FrenchSubstitutionsBuilder {
+    groups: Vec<FrenchRuleGroup>,
+    custom: SubstitutionsTable,
+}
+
+impl FrenchSubstitutionsBuilder {
+    /// Builder without any substitution
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Preset folding the ligatures, the quotes and the apostrophes, used by [`FrenchSubstitutions::new`]
+    pub fn folding() -> Self {
+        Self::new()
+            .with(FrenchRuleGroup::Ligatures)
+            .with(FrenchRuleGroup::Quotes)
+            .with(FrenchRuleGroup::Apostrophes)
+    }
+
+    /// Enables a group of substitutions
+    pub fn with(mut self, group: FrenchRuleGroup) -> Self {
+        if !self.groups.contains(&group) {
+            self.groups.push(group);
+            self.groups.sort();
+        }
+        self
+    }
+
+    /// Disables a group of substitutions
+    pub fn without(mut self, group: FrenchRuleGroup) -> Self {
+        self.groups.retain(|enabled| *enabled != group);
+        self
+    }
+
+    /// Adds a custom substitution
+    pub fn substitution(mut self, substitution: Substitution) -> Self {
+        self.custom.push(substitution);
+        self
+    }
+
+    /// Adds custom substitutions
+    pub fn substitutions<T: Into<SubstitutionsTable>>(mut self, substitutions: T) -> Self {
+        self.custom.merge(&substitutions.into());
+        self
+    }
+
+    pub fn build(&self) -> FrenchSubstitutions {
+        let table = self
+            .groups
+            .iter()
+            .fold(self.custom.clone(), |mut table, group| {
+                table.merge(&group.substitutions().into());
+                table
+            });
+
+        FrenchSubstitutions {
+            substitute: Substitute::new(&table),
+        }
+    }
+}
+
 /// Substitutions for the French language
+///
+/// [`FrenchSubstitutions::new`] folds the ligatures, the quotes and the apostrophes, other
+/// substitutions can be built with [`FrenchSubstitutions::builder`].
+#[derive(Clone)]
 pub struct FrenchSubstitutions {
-    substitutions_list: SubstitutionsList,
-    unicode_block_scope: Option<String>,
+    substitute: Substitute,
 }
 
 impl FrenchSubstitutions {
     pub fn new() -> FrenchSubstitutions {
-        let substitutions_list = substitutions! {
-            'œ' => "oe",
-            'Œ' => "OE",
-            'æ' => "ae",
-            'Æ' => "AE",
-            '`' => "'",
-            '’' => "'",
-            '“' => "\"",
-            '”' => "\"",
-            '‘' => "'",
-            '’' => "'",
-            '«' => "\"",
-            '»' => "\"",
-        };
+        FrenchSubstitutionsBuilder::folding().build()
+    }
 
-        FrenchSubstitutions {
-            substitutions_list,
-            unicode_block_scope: None,
-        }
+    /// Builder starting from the substitutions of [`FrenchSubstitutions::new`]
+    pub fn builder() -> FrenchSubstitutionsBuilder {
+        FrenchSubstitutionsBuilder::folding()
+    }
+
+    /// Only applies the substitutions to the chars within a unicode block or script
+    pub fn unicode_block_scope(mut self, scope: UnicodeScope) -> Self {
+        self.substitute = self.substitute.unicode_block_scope(scope);
+        self
+    }
+}
+
+impl Default for FrenchSubstitutions {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl<'a> From<&'a FrenchSubstitutions> for Substitute<'a> {
-    fn from(substitutions: &'a FrenchSubstitutions) -> Self {
-        Substitute::new(
-            &substitutions.substitutions_list,
-            &substitutions.unicode_block_scope,
-        )
+impl From<&FrenchSubstitutions> for Substitute {
+    fn from(substitutions: &FrenchSubstitutions) -> Self {
+        substitutions.substitute.clone()
     }
 }
 
@@ -52,17 +175,197 @@ impl Clean for FrenchSubstitutions {
     type Data = String;
 
     fn clean(&self, data: &mut Self::Data) {
-        let substitueur: Substitute = self.into();
-        substitueur.clean(data);
+        self.substitute.clean(data);
     }
 }
 
+/// How [`FrenchTypography`] deals with the spaces around the punctuation
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum FrenchTypographyMode {
+    /// Inserts the missing spaces
+    #[default]
+    Insert,
+    /// Only replaces the existing spaces before the high punctuation and inside the guillemets
+    Convert,
+}
+
 /// Normalize for the French language
-pub struct FrenchTypography;
+///
+/// By default regular spaces are used, with [`FrenchTypography::no_break_spaces`] a narrow no-break
+/// space (U+202F) is used before `; ! ?` and a no-break space (U+00A0) before `:` and inside `« »`.
+///
+/// In the [`FrenchTypographyMode::Insert`] mode a space is inserted before `» ( : ; ! ? «` unless a
+/// whitespace, no-break spaces included, is already there, and after `. « , ) : ;` unless a
+/// whitespace or a punctuation mark follows: the existing spaces are never removed. The runs of
+/// punctuation (`Oui!!`, `Quoi?!`), the numbers (`3,5`, `10:30`) and the URLs are left as they are.
+/// With the no-break spaces, the existing spaces before the high punctuation and inside the
+/// guillemets are converted first.
+///
+/// ```
+/// use textual_data_cleaner::french::{FrenchTypography, FrenchTypographyMode};
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("«Vraiment? Oui!»");
+/// FrenchTypography::new()
+///     .no_break_spaces(true)
+///     .normalize(&mut data);
+///
+/// assert_eq!(data, "«\u{a0}Vraiment\u{202f}? Oui\u{202f}!\u{a0}»");
+///
+/// let mut data = String::from("« Vraiment ? Oui!»");
+/// FrenchTypography::new()
+///     .no_break_spaces(true)
+///     .mode(FrenchTypographyMode::Convert)
+///     .normalize(&mut data);
+///
+/// assert_eq!(data, "«\u{a0}Vraiment\u{202f}? Oui!»");
+/// ```
+#[derive(Clone)]
+pub struct FrenchTypography {
+    no_break_spaces: bool,
+    mode: FrenchTypographyMode,
+    conversion: SpaceAround,
+}
 
 impl FrenchTypography {
     pub fn new() -> FrenchTypography {
-        FrenchTypography
+        FrenchTypography {
+            no_break_spaces: false,
+            mode: FrenchTypographyMode::Insert,
+            conversion: Self::conversion_rules(false),
+        }
+    }
+
+    /// Uses no-break spaces before the high punctuation and inside the guillemets
+    pub fn no_break_spaces(mut self, no_break_spaces: bool) -> Self {
+        self.no_break_spaces = no_break_spaces;
+        self.conversion = Self::conversion_rules(no_break_spaces);
+        self
+    }
+
+    pub fn mode(mut self, mode: FrenchTypographyMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Returns the narrow space used before `; ! ?` and the space used before `: »` and after `«`
+    fn spacings(no_break_spaces: bool) -> (Spacing, Spacing) {
+        if no_break_spaces {
+            (Spacing::NarrowNoBreakSpace, Spacing::NoBreakSpace)
+        } else {
+            (Spacing::Space, Spacing::Space)
+        }
+    }
+
+    /// Replaces the existing spaces before the high punctuation and inside the guillemets
+    fn conversion_rules(no_break_spaces: bool) -> SpaceAround {
+        let (narrow_space, space) = Self::spacings(no_break_spaces);
+
+        SpaceAround::new()
+            .before([';', '!', '?'], narrow_space)
+            .before([':', '»'], space)
+            .after(['«'], space)
+            .replace_only()
+    }
+
+    /// Inserts the missing spaces, without removing the existing ones
+    fn insert_spaces(&self, data: &mut String) {
+        let (narrow_space, space) = Self::spacings(self.no_break_spaces);
+        let narrow_space = narrow_space.space_char().unwrap_or(' ');
+        let space = space.space_char().unwrap_or(' ');
+
+        let char_starting_with_space = [
+            ('»', space),
+            ('(', ' '),
+            (':', space),
+            (';', narrow_space),
+            ('!', narrow_space),
+            ('?', narrow_space),
+            ('«', ' '),
+        ];
+        let char_ending_with_space = [
+            ('.', ' '),
+            ('«', space),
+            (',', ' '),
+            (')', ' '),
+            (':', ' '),
+            (';', ' '),
+        ];
+
+        for (elem, space) in char_starting_with_space {
+            PrependBeforeCharIf::with_context(elem, space, needs_space_before).normalize(data);
+        }
+
+        for (elem, space) in char_ending_with_space {
+            AppendAfterCharIf::with_context(elem, space, needs_space_after).normalize(data);
+        }
+    }
+}
+
+/// Punctuation marks after which the high punctuation takes no space, to keep the runs of
+/// punctuation together: `Oui!!`, `Quoi?!`
+const RUN_PUNCTUATION: [char; 4] = [':', ';', '!', '?'];
+
+/// Punctuation marks which take no space before them when they follow another one: `(e).`, `),`
+const CLOSING_PUNCTUATION: [char; 9] = ['.', '…', ',', ':', ';', '!', '?', '»', ')'];
+
+/// Returns true if the target char separates two digits, as in `3,5`, `3.5` or `10:30`
+fn is_between_digits(context: &InsertionContext) -> bool {
+    let is_digit = |c: Option<char>| c.is_some_and(|c| c.is_ascii_digit());
+
+    is_digit(context.previous_char()) && is_digit(context.next_char())
+}
+
+/// Returns true if the target char belongs to a URL: `http://x.fr`, `www.x.fr`
+fn is_in_url(context: &InsertionContext) -> bool {
+    let before = context
+        .before()
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or("");
+    let after = context
+        .after()
+        .split(char::is_whitespace)
+        .next()
+        .unwrap_or("");
+    let word = format!("{}{}{}", before, context.target_char(), after);
+
+    word.contains("://") || word.trim_start_matches(['(', '«', '"']).starts_with("www.")
+}
+
+/// Condition of the spaces inserted before `» ( : ; ! ? «`
+fn needs_space_before(context: &InsertionContext) -> bool {
+    let target = context.target_char();
+    let previous_char = context.previous_char();
+
+    if previous_char.is_some_and(char::is_whitespace) || is_in_url(context) {
+        return false;
+    }
+
+    match target {
+        ':' if is_between_digits(context) => false,
+        ':' | ';' | '!' | '?' => !previous_char.is_some_and(|c| RUN_PUNCTUATION.contains(&c)),
+        _ => true,
+    }
+}
+
+/// Condition of the spaces inserted after `. « , ) : ;`
+fn needs_space_after(context: &InsertionContext) -> bool {
+    if context
+        .next_char()
+        .is_some_and(|c| c.is_whitespace() || CLOSING_PUNCTUATION.contains(&c))
+        || is_in_url(context)
+    {
+        return false;
+    }
+
+    !(matches!(context.target_char(), '.' | ',' | ':') && is_between_digits(context))
+}
+
+impl Default for FrenchTypography {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -70,30 +373,86 @@ impl Normalize for FrenchTypography {
     type Data = String;
 
     fn normalize(&self, data: &mut Self::Data) {
-        let char_starting_with_space = ['»', '(', ':', ';', '!', '?', '«'];
-        let char_ending_with_space = ['.', '«', ',', ')', ':', ';'];
-
-        let test_next_char_is_space_or_punct =
-            move |next_char: Option<char>, matching_char: char, _replacement: char| {
-                let french_punct = ['.'];
-
-                if let Some(next_char) = next_char {
-                    println!("matching char `{}` next `{}`", matching_char, next_char,);
-                    !next_char.is_whitespace() && !french_punct.contains(&next_char)
-                } else {
-                    true
+        match self.mode {
+            FrenchTypographyMode::Insert => {
+                if self.no_break_spaces {
+                    self.conversion.normalize(data);
                 }
-            };
 
-        for elem in char_starting_with_space {
-            PrependBeforeCharIfDifferent::new(elem, ' ').normalize(data);
+                self.insert_spaces(data);
+                SpaceTrimmer::new().clean(data);
+            }
+            FrenchTypographyMode::Convert => self.conversion.normalize(data),
         }
+    }
+}
 
-        for elem in char_ending_with_space {
-            AppendAfterCharIf::new(elem, ' ', test_next_char_is_space_or_punct).normalize(data);
+/// Removes the French spaces before the high punctuation and inside the guillemets
+///
+/// Regular, no-break and narrow no-break spaces are removed, so that `« texte : ici »` becomes
+/// `«texte: ici»`, or `"texte: ici"` with [`FrenchSpaceRemover::straight_quotes`].
+/// Without straight quotes, [`FrenchTypography`] restores a text that it had formatted.
+///
+/// ```
+/// use textual_data_cleaner::french::{FrenchSpaceRemover, FrenchTypography};
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("« texte\u{a0}: ici\u{202f}! »");
+/// FrenchSpaceRemover::new().normalize(&mut data);
+///
+/// assert_eq!(data, "«texte: ici!»");
+///
+/// FrenchTypography::new().normalize(&mut data);
+///
+/// assert_eq!(data, "« texte : ici ! »");
+/// ```
+#[derive(Clone)]
+pub struct FrenchSpaceRemover {
+    space_around: SpaceAround,
+    straight_quotes: Option<Substitute>,
+}
+
+impl FrenchSpaceRemover {
+    pub fn new() -> FrenchSpaceRemover {
+        FrenchSpaceRemover {
+            space_around: SpaceAround::new()
+                .before([':', ';', '!', '?', '»'], Spacing::None)
+                .after(['«'], Spacing::None),
+            straight_quotes: None,
         }
+    }
+
+    /// Replaces the guillemets with straight quotes
+    pub fn straight_quotes(mut self, straight_quotes: bool) -> Self {
+        self.straight_quotes = if straight_quotes {
+            let substitutions_list = substitutions! {
+                '«' => "\"",
+                '»' => "\"",
+            };
+
+            Some(Substitute::new(&substitutions_list))
+        } else {
+            None
+        };
+        self
+    }
+}
+
+impl Default for FrenchSpaceRemover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Normalize for FrenchSpaceRemover {
+    type Data = String;
 
-        SpaceTrimmer::new().clean(data);
+    fn normalize(&self, data: &mut Self::Data) {
+        self.space_around.normalize(data);
+
+        if let Some(straight_quotes) = &self.straight_quotes {
+            straight_quotes.clean(data);
+        }
     }
 }
 
@@ -110,4 +469,180 @@ mod tests {
 
         assert_eq!(input, expected);
     }
+
+    #[test]
+    fn test_french_typography_multiple_punctuation() {
+        let mut input = "Lui: oui; elle: non; eux: peut-être!".to_string();
+        let expected = "Lui : oui ; elle : non ; eux : peut-être !".to_string();
+
+        FrenchTypography::new().normalize(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_french_typography_keeps_existing_spaces() {
+        let mut input = "Lui  :oui ;  « non »  , peut-être\u{a0}!".to_string();
+        let expected = "Lui  : oui ;  « non »  , peut-être\u{a0}!".to_string();
+
+        FrenchTypography::new().normalize(&mut input);
+
+        assert_eq!(input, expected);
+
+        let mut input = "oui\u{202f}! «\u{a0}non\u{a0}»".to_string();
+        let expected = input.clone();
+
+        FrenchTypography::new().normalize(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_french_typography_punctuation_runs_numbers_and_urls() {
+        let typography = |input: &str| {
+            let mut data = input.to_string();
+            FrenchTypography::new().normalize(&mut data);
+            data
+        };
+
+        assert_eq!(typography("Oui!!"), "Oui !!");
+        assert_eq!(typography("Quoi?!"), "Quoi ?!");
+        assert_eq!(typography("Et alors...?"), "Et alors... ?");
+        assert_eq!(typography("3,5 kg à 10:30, 2.5 l"), "3,5 kg à 10:30, 2.5 l");
+        assert_eq!(
+            typography("Voir http://x.fr (www.x.fr)."),
+            "Voir http://x.fr (www.x.fr)."
+        );
+        assert_eq!(typography("(dit-il),oui"), "(dit-il), oui");
+    }
+
+    #[test]
+    fn test_french_typography_no_break_spaces() {
+        let mut input = "«Lui: oui; elle: non; eux: peut-être!»".to_string();
+        let expected = "«\u{a0}Lui\u{a0}: oui\u{202f}; elle\u{a0}: non\u{202f}; eux\u{a0}: peut-être\u{202f}!\u{a0}»";
+
+        FrenchTypography::new()
+            .no_break_spaces(true)
+            .normalize(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_french_typography_no_break_spaces_already_spaced() {
+        let input = "«\u{a0}Lui\u{202f}: oui\u{a0}! » (non\u{202f}?)";
+
+        for (no_break_spaces, expected) in [
+            (false, input),
+            (true, "«\u{a0}Lui\u{a0}: oui\u{202f}!\u{a0}» (non\u{202f}?)"),
+        ] {
+            let mut data = input.to_string();
+
+            FrenchTypography::new()
+                .no_break_spaces(no_break_spaces)
+                .normalize(&mut data);
+
+            assert_eq!(data, expected);
+        }
+    }
+
+    #[test]
+    fn test_french_typography_convert() {
+        let mut input = "« Lui : oui\u{a0}; elle:non ;  eux ? »\n".to_string();
+        let expected = "«\u{a0}Lui\u{a0}: oui\u{202f}; elle:non\u{202f};  eux\u{202f}?\u{a0}»\n";
+
+        FrenchTypography::new()
+            .no_break_spaces(true)
+            .mode(FrenchTypographyMode::Convert)
+            .normalize(&mut input);
+
+        assert_eq!(input, expected);
+
+        let mut input = "«\u{a0}Lui\u{202f}: oui\u{202f}!\u{a0}»".to_string();
+        let expected = "« Lui : oui ! »";
+
+        FrenchTypography::new()
+            .mode(FrenchTypographyMode::Convert)
+            .normalize(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_french_space_remover() {
+        let mut input = "« texte : ici » ; là\u{202f}?\u{a0}!".to_string();
+        let expected = "«texte: ici»; là?!".to_string();
+
+        FrenchSpaceRemover::new().normalize(&mut input);
+
+        assert_eq!(input, expected);
+
+        let mut input = "« texte : ici »".to_string();
+        let expected = "\"texte: ici\"".to_string();
+
+        FrenchSpaceRemover::new()
+            .straight_quotes(true)
+            .normalize(&mut input);
+
+        assert_eq!(input, expected);
+    }
+
+    #[test]
+    fn test_french_space_remover_round_trip() {
+        for typography in [
+            FrenchTypography::new(),
+            FrenchTypography::new().no_break_spaces(true),
+        ] {
+            let mut formatted = "« Lui : oui ; elle ? Non ! » (dit-il). Enfin, oui.".to_string();
+            typography.normalize(&mut formatted);
+
+            let mut data = formatted.clone();
+            FrenchSpaceRemover::new().normalize(&mut data);
+
+            assert_eq!(data, "«Lui: oui; elle? Non!» (dit-il). Enfin, oui.");
+
+            typography.normalize(&mut data);
+
+            assert_eq!(data, formatted);
+        }
+    }
+
+    #[test]
+    fn test_french_substitutions_preset() {
+        let mut data = "Œuvre «cœur» “l’æther” `a‘ — …".to_string();
+
+        FrenchSubstitutions::new().clean(&mut data);
+
+        assert_eq!(data, "OEuvre \"coeur\" \"l'aether\" 'a' — …");
+    }
+
+    #[test]
+    fn test_french_substitutions_groups() {
+        let substitutions = FrenchSubstitutionsBuilder::new()
+            .with(FrenchRuleGroup::Dashes)
+            .with(FrenchRuleGroup::Quotes)
+            .with(FrenchRuleGroup::Dashes)
+            .build();
+
+        let mut data = "«cœur» – l’été — ici".to_string();
+        substitutions.clean(&mut data);
+
+        assert_eq!(data, "\"cœur\" - l’été - ici");
+    }
+
+    #[test]
+    fn test_french_substitutions_custom_entries() {
+        let substitutions = FrenchSubstitutions::builder()
+            .without(FrenchRuleGroup::Quotes)
+            .substitutions(substitutions! {
+                'œ' => "œ",
+                "<<" => "«",
+            })
+            .build();
+
+        let mut data = "<<cœur» æ".to_string();
+        substitutions.clean(&mut data);
+
+        assert_eq!(data, "«cœur» ae");
+    }
 }
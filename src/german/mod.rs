@@ -0,0 +1,327 @@
+use serde::Deserialize;
+
+use crate::clean::Clean;
+use crate::common::{SpaceAround, Spacing};
+use crate::languages::latin_substitutions;
+use crate::normalize::Normalize;
+use crate::substitutions;
+use crate::substitutions::{Substitute, SubstitutionsTable};
+
+/// Handling of the sharp s `ß` and of its capital form `ẞ`
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SharpS {
+    /// Keeps `ß` and `ẞ`
+    #[default]
+    Keep,
+    /// Expands `ß` to `ss` and `ẞ` to `SS`
+    Expand,
+}
+
+/// Style of the German quotes
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum GermanQuotes {
+    /// Keeps the quotes as they are
+    #[default]
+    Keep,
+    /// `„…“` and `‚…‘`
+    Low,
+    /// `»…«` and `›…‹`
+    Guillemets,
+    /// `"…"` and `'…'`
+    Straight,
+}
+
+/// Substitutions for the German language
+///
+/// The ligatures are expanded and the apostrophes folded, the sharp s, the umlauts and the
+/// quotes are kept unless configured otherwise.
+///
+/// ```
+/// use textual_data_cleaner::german::{GermanQuotes, GermanSubstitutions, SharpS};
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("»Grüße«, sagt’s");
+/// GermanSubstitutions::new()
+///     .sharp_s(SharpS::Expand)
+///     .transliterate_umlauts(true)
+///     .quotes(GermanQuotes::Low)
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "„Gruesse“, sagt's");
+/// ```
+#[derive(Clone)]
+pub struct GermanSubstitutions {
+    sharp_s: SharpS,
+    transliterate_umlauts: bool,
+    quotes: GermanQuotes,
+    substitute: Substitute,
+}
+
+impl GermanSubstitutions {
+    pub fn new() -> GermanSubstitutions {
+        let sharp_s = SharpS::Keep;
+        let quotes = GermanQuotes::Keep;
+
+        GermanSubstitutions {
+            sharp_s,
+            transliterate_umlauts: false,
+            quotes,
+            substitute: Self::substitute(sharp_s, false, quotes),
+        }
+    }
+
+    pub fn sharp_s(mut self, sharp_s: SharpS) -> Self {
+        self.sharp_s = sharp_s;
+        self.rebuild()
+    }
+
+    /// Transliterates the umlauts: `ä` to `ae`, `Ä` to `Ae`, or to `AE` in an all-caps word, etc.
+    pub fn transliterate_umlauts(mut self, transliterate_umlauts: bool) -> Self {
+        self.transliterate_umlauts = transliterate_umlauts;
+        self.rebuild()
+    }
+
+    /// Converts the German quotes to a single style
+    pub fn quotes(mut self, quotes: GermanQuotes) -> Self {
+        self.quotes = quotes;
+        self.rebuild()
+    }
+
+    fn rebuild(mut self) -> Self {
+        self.substitute = Self::substitute(self.sharp_s, self.transliterate_umlauts, self.quotes);
+        self
+    }
+
+    fn substitute(
+        sharp_s: SharpS,
+        transliterate_umlauts: bool,
+        quotes: GermanQuotes,
+    ) -> Substitute {
+        let mut table = SubstitutionsTable::from(latin_substitutions());
+
+        if sharp_s == SharpS::Expand {
+            table.merge(
+                &substitutions! {
+                    'ß' => "ss",
+                    'ẞ' => "SS",
+                }
+                .into(),
+            );
+        }
+
+        if transliterate_umlauts {
+            table.merge(
+                &substitutions! {
+                    'ä', "a\u{308}" => "ae",
+                    'ö', "o\u{308}" => "oe",
+                    'ü', "u\u{308}" => "ue",
+                }
+                .into(),
+            );
+        }
+
+        match quotes {
+            GermanQuotes::Keep => {}
+            GermanQuotes::Low => table.merge(
+                &substitutions! {
+                    '»' => "„",
+                    '«' => "“",
+                    '›' => "‚",
+                    '‹' => "‘",
+                }
+                .into(),
+            ),
+            GermanQuotes::Guillemets => table.merge(
+                &substitutions! {
+                    '„' => "»",
+                    '“' => "«",
+                    '‚' => "›",
+                    '‘' => "‹",
+                }
+                .into(),
+            ),
+            GermanQuotes::Straight => table.merge(
+                &substitutions! {
+                    '„', '“', '»', '«' => "\"",
+                    '‚', '‘', '›', '‹' => "'",
+                }
+                .into(),
+            ),
+        }
+
+        Substitute::new(&table)
+    }
+}
+
+impl Default for GermanSubstitutions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clean for GermanSubstitutions {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        if self.transliterate_umlauts {
+            transliterate_capital_umlauts(data);
+        }
+
+        self.substitute.clean(data);
+    }
+}
+
+/// Transliterates the capital umlauts, composed or decomposed, to `AE`, `OE` and `UE` in the
+/// all-caps words (`ÄRGER` to `AERGER`) and to `Ae`, `Oe` and `Ue` otherwise
+///
+/// A word is all caps when the letter following the umlaut is uppercase or, at the end of a word,
+/// when the letter preceding it is uppercase.
+fn transliterate_capital_umlauts(data: &mut String) {
+    if !data.contains(['Ä', 'Ö', 'Ü', '\u{308}']) {
+        return;
+    }
+
+    let chars: Vec<char> = data.chars().collect();
+    let mut output = String::with_capacity(data.len() + 8);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let (base, len) = match chars[i] {
+            'Ä' => ('A', 1),
+            'Ö' => ('O', 1),
+            'Ü' => ('U', 1),
+            c @ ('A' | 'O' | 'U') if chars.get(i + 1) == Some(&'\u{308}') => (c, 2),
+            c => {
+                output.push(c);
+                i += 1;
+                continue;
+            }
+        };
+
+        let all_caps = match chars.get(i + len) {
+            Some(next) if next.is_alphabetic() => next.is_uppercase(),
+            _ => i
+                .checked_sub(1)
+                .is_some_and(|previous| chars[previous].is_uppercase()),
+        };
+
+        output.push(base);
+        output.push(if all_caps { 'E' } else { 'e' });
+        i += len;
+    }
+
+    *data = output;
+}
+
+/// Normalize for the German language
+///
+/// Removes the spaces before the punctuation and inside the German quotes.
+///
+/// ```
+/// use textual_data_cleaner::german::GermanTypography;
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("Er fragt : „ Warum ? “");
+/// GermanTypography::new().normalize(&mut data);
+///
+/// assert_eq!(data, "Er fragt: „Warum?“");
+/// ```
+#[derive(Clone)]
+pub struct GermanTypography {
+    space_around: SpaceAround,
+}
+
+impl GermanTypography {
+    pub fn new() -> GermanTypography {
+        GermanTypography {
+            space_around: SpaceAround::new()
+                .before([',', '.', ':', ';', '!', '?'], Spacing::None)
+                .after(['„', '‚', '»', '›'], Spacing::None)
+                .before(['“', '‘', '«', '‹'], Spacing::None),
+        }
+    }
+}
+
+impl Default for GermanTypography {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Normalize for GermanTypography {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        self.space_around.normalize(data);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_german_substitutions_default() {
+        let mut data = "„Straße“ ‚Öl‘ »Œuvre« ẞ".to_string();
+
+        GermanSubstitutions::new().clean(&mut data);
+
+        assert_eq!(data, "„Straße“ ‚Öl‘ »OEuvre« ẞ");
+    }
+
+    #[test]
+    fn test_german_sharp_s_and_umlauts() {
+        let mut data = "GROẞE Straße, Übel ärgern, Bo\u{308}ller".to_string();
+
+        GermanSubstitutions::new()
+            .sharp_s(SharpS::Expand)
+            .transliterate_umlauts(true)
+            .clean(&mut data);
+
+        assert_eq!(data, "GROSSE Strasse, Uebel aergern, Boeller");
+    }
+
+    #[test]
+    fn test_german_all_caps_umlauts() {
+        let mut data = "ÄRGER, U\u{308}BEL, MÄ, Ö, ÖL und Öl".to_string();
+
+        GermanSubstitutions::new()
+            .transliterate_umlauts(true)
+            .clean(&mut data);
+
+        assert_eq!(data, "AERGER, UEBEL, MAE, Oe, OEL und Oel");
+    }
+
+    #[test]
+    fn test_german_quotes() {
+        let quotes = |style: GermanQuotes| {
+            let mut data = "„Er sagte ‚ja‘.“ »Sie sagte ›nein‹.«".to_string();
+            GermanSubstitutions::new().quotes(style).clean(&mut data);
+            data
+        };
+
+        assert_eq!(
+            quotes(GermanQuotes::Low),
+            "„Er sagte ‚ja‘.“ „Sie sagte ‚nein‘.“"
+        );
+        assert_eq!(
+            quotes(GermanQuotes::Guillemets),
+            "»Er sagte ›ja‹.« »Sie sagte ›nein‹.«"
+        );
+        assert_eq!(
+            quotes(GermanQuotes::Straight),
+            "\"Er sagte 'ja'.\" \"Sie sagte 'nein'.\""
+        );
+    }
+
+    #[test]
+    fn test_german_typography() {
+        let mut data = "Achtung ! Er sagte : » Nein ; ‚ nie ‘ « , dann ging er .".to_string();
+
+        GermanTypography::new().normalize(&mut data);
+
+        assert_eq!(data, "Achtung! Er sagte: »Nein; ‚nie‘«, dann ging er.");
+    }
+}
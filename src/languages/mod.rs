@@ -0,0 +1,355 @@
+//! Language packs grouping the substitutions and typography rules of a language
+
+use crate::common::{SpaceAround, Spacing};
+use crate::french::{FrenchSubstitutions, FrenchTypography};
+use crate::german::{GermanSubstitutions, GermanTypography};
+use crate::pipeline::Pipeline;
+use crate::substitutions;
+use crate::substitutions::{RegexSubstitute, Substitute, SubstitutionsList, SubstitutionsTable};
+use crate::whitespaces::SpaceTrimmer;
+
+/// Substitutions and typography rules of a language
+///
+/// ```
+/// use textual_data_cleaner::languages::language_pack;
+/// use textual_data_cleaner::Clean;
+///
+/// let spanish = language_pack("es").unwrap();
+///
+/// let mut data = String::from("¿ Qué ? ¡ Sí !");
+/// spanish.typography().clean(&mut data);
+///
+/// assert_eq!(data, "¿Qué? ¡Sí!");
+/// ```
+pub trait LanguagePack: Send + Sync {
+    /// ISO 639-1 code of the language
+    fn code(&self) -> &'static str;
+
+    /// English name of the language
+    fn name(&self) -> &'static str;
+
+    /// Substitutions of the language
+    fn substitutions(&self) -> Pipeline;
+
+    /// Typography rules of the language
+    fn typography(&self) -> Pipeline;
+}
+
+/// Codes of the available language packs
+pub const LANGUAGE_CODES: [&str; 6] = ["fr", "en", "de", "es", "it", "pt"];
+
+static LANGUAGE_PACKS: [&dyn LanguagePack; 6] =
+    [&French, &English, &German, &Spanish, &Italian, &Portuguese];
+
+/// Returns the language pack of an ISO 639-1 language code
+pub fn language_pack(code: &str) -> Option<&'static dyn LanguagePack> {
+    LANGUAGE_PACKS
+        .iter()
+        .find(|pack| pack.code().eq_ignore_ascii_case(code))
+        .copied()
+}
+
+/// Substitutions shared by the languages written in the latin script: the ligatures are expanded
+/// and the apostrophes folded
+pub(crate) fn latin_substitutions() -> SubstitutionsList {
+    substitutions! {
+        'œ' => "oe",
+        'Œ' => "OE",
+        'æ' => "ae",
+        'Æ' => "AE",
+        '`' => "'",
+        '’' => "'",
+        'ʼ' => "'",
+    }
+}
+
+/// Builds the substitutions of a language from its own substitutions followed by the shared ones
+fn substitutions_pipeline(substitutions_list: SubstitutionsList) -> Pipeline {
+    let mut table = SubstitutionsTable::from(substitutions_list);
+    table.merge(&latin_substitutions().into());
+
+    Pipeline::builder().clean(Substitute::new(&table)).build()
+}
+
+/// Typography of the languages that use no space before the punctuation and inside the
+/// quotation marks and the parentheses
+fn tight_punctuation(opening: &[char], closing: &[char]) -> SpaceAround {
+    SpaceAround::new()
+        .before([',', '.', ':', ';', '!', '?'], Spacing::None)
+        .after(opening.iter().copied().chain(['(']), Spacing::None)
+        .before(closing.iter().copied().chain([')']), Spacing::None)
+}
+
+/// French language pack, see [`FrenchSubstitutions`] and [`FrenchTypography`]
+pub struct French;
+
+impl LanguagePack for French {
+    fn code(&self) -> &'static str {
+        "fr"
+    }
+
+    fn name(&self) -> &'static str {
+        "French"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        Pipeline::builder()
+            .clean(FrenchSubstitutions::new())
+            .build()
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(FrenchTypography::new())
+            .build()
+    }
+}
+
+/// English language pack, the quotes `“…”` and `‘…’` are folded to straight ones, see
+/// [`EnglishTypography`](crate::english::EnglishTypography) to curl them instead
+pub struct English;
+
+impl LanguagePack for English {
+    fn code(&self) -> &'static str {
+        "en"
+    }
+
+    fn name(&self) -> &'static str {
+        "English"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        substitutions_pipeline(substitutions! {
+            '“', '”', '‟' => "\"",
+            '‘', '‛' => "'",
+        })
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(tight_punctuation(&['“'], &['”']))
+            .clean(SpaceTrimmer)
+            .build()
+    }
+}
+
+/// German language pack, see [`GermanSubstitutions`] and [`GermanTypography`]
+pub struct German;
+
+impl LanguagePack for German {
+    fn code(&self) -> &'static str {
+        "de"
+    }
+
+    fn name(&self) -> &'static str {
+        "German"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        Pipeline::builder()
+            .clean(GermanSubstitutions::new())
+            .build()
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(GermanTypography::new())
+            .build()
+    }
+}
+
+/// Spanish language pack
+///
+/// The quotes `«…»`, `“…”` and `‘…’` are folded to straight ones, the inverted marks `¿` and `¡`
+/// are kept and followed by no space.
+pub struct Spanish;
+
+impl LanguagePack for Spanish {
+    fn code(&self) -> &'static str {
+        "es"
+    }
+
+    fn name(&self) -> &'static str {
+        "Spanish"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        substitutions_pipeline(substitutions! {
+            '«', '»', '“', '”' => "\"",
+            '‘' => "'",
+        })
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(tight_punctuation(&['¿', '¡', '«', '“'], &['»', '”']))
+            .clean(SpaceTrimmer)
+            .build()
+    }
+}
+
+/// Words ending with an elided vowel in Italian, followed by an apostrophe and the next word
+const ITALIAN_ELISION: &str = r"(?i)\b(l|un|dell|all|dall|nell|sull|coll|quest|quell|bell|sant|tutt|c|d|m|n|s|t|v)(['’])\s+(\p{L})";
+
+/// Italian language pack
+///
+/// The quotes `«…»`, `“…”` and `‘…’` are folded to straight ones and the apostrophe of an elision
+/// is followed by no space: `l’ amico` to `l’amico`.
+pub struct Italian;
+
+impl LanguagePack for Italian {
+    fn code(&self) -> &'static str {
+        "it"
+    }
+
+    fn name(&self) -> &'static str {
+        "Italian"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        substitutions_pipeline(substitutions! {
+            '«', '»', '“', '”' => "\"",
+            '‘' => "'",
+        })
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(tight_punctuation(&['«', '“'], &['»', '”']))
+            .clean(RegexSubstitute::new(ITALIAN_ELISION, "$1$2$3").unwrap())
+            .clean(SpaceTrimmer)
+            .build()
+    }
+}
+
+/// Clitic pronouns joined to the verb by a hyphen in Portuguese, with a space on one side of the
+/// hyphen: a hyphen with a space on both sides is a dash
+const PORTUGUESE_CLITIC: &str = r"(\p{L})(?:\s+-|-\s+)(lhes?|se|me|te|nos|vos|[ln]?[oa]s?)\b";
+
+/// Portuguese language pack
+///
+/// The quotes `«…»` of European Portuguese and `“…”` of Brazilian Portuguese, as well as `‘…’`,
+/// are folded to straight ones, and the hyphen joining a clitic pronoun to the verb is surrounded
+/// by no space: `disse- lhe` to `disse-lhe`.
+pub struct Portuguese;
+
+impl LanguagePack for Portuguese {
+    fn code(&self) -> &'static str {
+        "pt"
+    }
+
+    fn name(&self) -> &'static str {
+        "Portuguese"
+    }
+
+    fn substitutions(&self) -> Pipeline {
+        substitutions_pipeline(substitutions! {
+            '«', '»', '“', '”' => "\"",
+            '‘' => "'",
+        })
+    }
+
+    fn typography(&self) -> Pipeline {
+        Pipeline::builder()
+            .normalize(tight_punctuation(&['«', '“'], &['»', '”']))
+            .clean(RegexSubstitute::new(PORTUGUESE_CLITIC, "$1-$2").unwrap())
+            .clean(SpaceTrimmer)
+            .build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clean::Clean;
+
+    #[test]
+    fn test_language_codes() {
+        for code in LANGUAGE_CODES {
+            assert_eq!(language_pack(code).unwrap().code(), code);
+        }
+
+        assert_eq!(language_pack("DE").unwrap().name(), "German");
+        assert!(language_pack("xx").is_none());
+    }
+
+    #[test]
+    fn test_german_quotes_are_kept() {
+        let german = language_pack("de").unwrap();
+
+        let mut data = String::from("Er sagte: „ Ja ! “ und ‚ nein ‘, ging’s an");
+        german.substitutions().clean(&mut data);
+        german.typography().clean(&mut data);
+
+        assert_eq!(data, "Er sagte: „Ja!“ und ‚nein‘, ging's an");
+    }
+
+    #[test]
+    fn test_french_pack() {
+        let french = language_pack("fr").unwrap();
+
+        let mut data = String::from("«Œuvre: “ici”!»");
+        french.substitutions().clean(&mut data);
+
+        assert_eq!(data, "\"OEuvre: \"ici\"!\"");
+
+        let mut data = String::from("«Œuvre: ici!»");
+        french.typography().clean(&mut data);
+
+        assert_eq!(data, "« Œuvre : ici ! »");
+    }
+
+    #[test]
+    fn test_spanish_pack() {
+        let spanish = language_pack("es").unwrap();
+
+        let mut data = String::from("« ¿ Qué ? » , dijo ( ¡ ya ! )");
+        spanish.typography().clean(&mut data);
+
+        assert_eq!(data, "«¿Qué?», dijo (¡ya!)");
+
+        spanish.substitutions().clean(&mut data);
+
+        assert_eq!(data, "\"¿Qué?\", dijo (¡ya!)");
+    }
+
+    #[test]
+    fn test_italian_pack() {
+        let italian = language_pack("it").unwrap();
+
+        let mut data = String::from("L’ amico dell' anno , c’ è « tutto » ; un’ isola");
+        italian.typography().clean(&mut data);
+
+        assert_eq!(data, "L’amico dell'anno, c’è «tutto»; un’isola");
+
+        italian.substitutions().clean(&mut data);
+
+        assert_eq!(data, "L'amico dell'anno, c'è \"tutto\"; un'isola");
+    }
+
+    #[test]
+    fn test_portuguese_pack() {
+        let portuguese = language_pack("pt").unwrap();
+
+        let mut data = String::from("Disse- lhe : “ vamos ” ; deu -o , foi- se");
+        portuguese.typography().clean(&mut data);
+
+        assert_eq!(data, "Disse-lhe: “vamos”; deu-o, foi-se");
+
+        let mut data = String::from("Ele saiu - o carro ficou");
+        portuguese.typography().clean(&mut data);
+
+        assert_eq!(data, "Ele saiu - o carro ficou");
+    }
+
+    #[test]
+    fn test_english_pack() {
+        let english = language_pack("en").unwrap();
+
+        let mut data = String::from("“Don’t” , she said ; «œuvre»");
+        english.substitutions().clean(&mut data);
+        english.typography().clean(&mut data);
+
+        assert_eq!(data, "\"Don't\", she said; «oeuvre»");
+    }
+}
@@ -0,0 +1,34 @@
+//! Tools to clean and normalize textual data.
+//!
+//! Every cleaner implements either [`Clean`] or [`Normalize`] and works in place on a `String`:
+//!
+//! ```
+//! use textual_data_cleaner::whitespaces::WhitespaceNormalizer;
+//! use textual_data_cleaner::Clean;
+//!
+//! let mut data = String::from(" lorem\t\tipsum ");
+//! WhitespaceNormalizer.clean(&mut data);
+//!
+//! assert_eq!(data, "lorem ipsum");
+//! ```
+
+pub mod ascii;
+pub mod case;
+pub mod clean;
+pub mod common;
+pub mod config;
+pub mod english;
+pub mod french;
+pub mod german;
+pub mod languages;
+pub mod normalize;
+pub mod pipeline;
+pub mod substitutions;
+pub mod unicode;
+pub mod utils;
+pub mod whitespaces;
+
+pub use clean::Clean;
+pub use normalize::Normalize;
+pub use pipeline::Pipeline;
+pub use substitutions::{RegexSubstitute, Substitute, SubstitutionsList, SubstitutionsTable};
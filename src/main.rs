@@ -1,80 +1,282 @@
-use std::ops::RangeInclusive;
-use unicode_normalization::UnicodeNormalization;
+use std::error::Error;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
 
-mod whitespaces;
-use whitespaces::*;
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, ValueEnum};
 
-mod common;
-use common::*;
+use textual_data_cleaner::ascii::AsciiTransliterator;
+use textual_data_cleaner::case::{Case, CaseNormalizer};
+use textual_data_cleaner::common::ControlCharRemover;
+use textual_data_cleaner::config::PipelineConfig;
+use textual_data_cleaner::english::{EnglishQuotes, EnglishTypography};
+use textual_data_cleaner::french::{FrenchSpaceRemover, FrenchSubstitutions, FrenchTypography};
+use textual_data_cleaner::german::{GermanSubstitutions, GermanTypography};
+use textual_data_cleaner::languages::{language_pack, LanguagePack, LANGUAGE_CODES};
+use textual_data_cleaner::pipeline::{Pipeline, PipelineBuilder};
+use textual_data_cleaner::unicode::{
+    DiacriticRemover, NfcNormalizer, NfdNormalizer, NfkcNormalizer, NfkdNormalizer,
+    SuperscriptConverter,
+};
+use textual_data_cleaner::whitespaces::*;
+use textual_data_cleaner::Clean;
 
-mod utils;
-use utils::*;
+/// Cleans textual data read from files or from stdin
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Files to clean, stdin is read if no file is given
+    inputs: Vec<PathBuf>,
 
-mod clean;
-mod normalize;
+    /// Writes the cleaned text to this file instead of stdout
+    #[arg(short, long)]
+    output: Option<PathBuf>,
 
-use clean::*;
-use normalize::Normalize;
+    /// Loads the cleaning steps from a pipeline configuration file (.toml, .yaml, .yml or .json)
+    #[arg(short, long, conflicts_with = "steps")]
+    config: Option<PathBuf>,
 
-mod french;
-use french::FrenchSubstitutions;
+    /// Cleaning steps to apply, in order (repeat the flag or separate steps with commas)
+    #[arg(
+        short,
+        long = "step",
+        value_enum,
+        value_delimiter = ',',
+        default_values = [
+            "nfkc-normalizer",
+            "substitutions",
+            "control-char-remover",
+            "consecutive-white-space-remover",
+            "space-normalizer",
+        ]
+    )]
+    steps: Vec<Step>,
 
-mod substitutions;
-use substitutions::{substitutions, Substitute};
+    /// Language of the `substitutions` and `typography` steps
+    #[arg(
+        short,
+        long,
+        default_value = "fr",
+        value_parser = PossibleValuesParser::new(LANGUAGE_CODES)
+    )]
+    lang: String,
 
-// substituer les exposants !
-// substituer subscript
+    /// Cleans the whole input at once instead of line by line
+    #[arg(short, long)]
+    whole: bool,
+}
 
-fn main() {
-    let input = &mut "œﬃ «’eee\teee\t\t   eee";
-    let mut x = input.nfkc().collect::<String>();
+/// A cleaning step that can be selected from the command line
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum Step {
+    /// Applies the NFC unicode normalization
+    NfcNormalizer,
+    /// Applies the NFD unicode normalization
+    NfdNormalizer,
+    /// Applies the NFKC unicode normalization
+    NfkcNormalizer,
+    /// Applies the NFKD unicode normalization
+    NfkdNormalizer,
+    /// Removes the diacritics
+    DiacriticRemover,
+    /// Applies the substitutions of the language selected with `--lang`
+    Substitutions,
+    /// Applies the typography rules of the language selected with `--lang`
+    Typography,
+    /// Applies the substitutions for the French language
+    FrenchSubstitutions,
+    /// Applies the French typography rules
+    FrenchTypography,
+    /// Removes the French spaces before the high punctuation and inside the guillemets
+    FrenchSpaceRemover,
+    /// Converts the straight quotes and apostrophes to curly ones, following the English rules
+    EnglishCurlyQuotes,
+    /// Converts the curly quotes and apostrophes to straight ones
+    EnglishStraightQuotes,
+    /// Applies the substitutions for the German language
+    GermanSubstitutions,
+    /// Applies the German typography rules
+    GermanTypography,
+    /// Converts the superscripts and subscripts to plain chars
+    SuperscriptConverter,
+    /// Transliterates the text to ASCII, removing the chars that can't be transliterated
+    AsciiTransliterator,
+    /// Converts the text to lowercase
+    Lowercase,
+    /// Converts the text to uppercase
+    Uppercase,
+    /// Applies the full unicode case folding, for case-insensitive matching
+    CaseFold,
+    /// Removes all unicode control characters
+    ControlCharRemover,
+    /// Removes all consecutive spaces
+    ConsecutiveWhiteSpaceRemover,
+    /// Replaces all kind of spaces with a standard space
+    SpaceNormalizer,
+    /// Removes leading and trailing spaces
+    SpaceTrimmer,
+    /// Normalizes, deduplicates and trims spaces
+    WhitespaceNormalizer,
+}
 
-    FrenchSubstitutions::new().clean(&mut x);
-    ControlCharRemover.clean(&mut x);
-    ConsecutiveWhiteSpaceRemover.clean(&mut x);
-    SpaceNormalizer.normalize(&mut x);
+impl Step {
+    fn add_to(&self, builder: PipelineBuilder, language: &dyn LanguagePack) -> PipelineBuilder {
+        match self {
+            Step::NfcNormalizer => builder.normalize(NfcNormalizer),
+            Step::NfdNormalizer => builder.normalize(NfdNormalizer),
+            Step::NfkcNormalizer => builder.normalize(NfkcNormalizer),
+            Step::NfkdNormalizer => builder.normalize(NfkdNormalizer),
+            Step::DiacriticRemover => builder.normalize(DiacriticRemover::new()),
+            Step::Substitutions => builder.pipeline(&language.substitutions()),
+            Step::Typography => builder.pipeline(&language.typography()),
+            Step::FrenchSubstitutions => builder.clean(FrenchSubstitutions::new()),
+            Step::FrenchTypography => builder.normalize(FrenchTypography::new()),
+            Step::FrenchSpaceRemover => builder.normalize(FrenchSpaceRemover::new()),
+            Step::EnglishCurlyQuotes => {
+                builder.normalize(EnglishTypography::new().quotes(EnglishQuotes::Curly))
+            }
+            Step::EnglishStraightQuotes => {
+                builder.normalize(EnglishTypography::new().quotes(EnglishQuotes::Straight))
+            }
+            Step::GermanSubstitutions => builder.clean(GermanSubstitutions::new()),
+            Step::GermanTypography => builder.normalize(GermanTypography::new()),
+            Step::SuperscriptConverter => builder.clean(SuperscriptConverter::new()),
+            Step::AsciiTransliterator => builder.clean(AsciiTransliterator::new()),
+            Step::Lowercase => builder.normalize(CaseNormalizer::new(Case::Lower)),
+            Step::Uppercase => builder.normalize(CaseNormalizer::new(Case::Upper)),
+            Step::CaseFold => builder.normalize(CaseNormalizer::new(Case::Fold)),
+            Step::ControlCharRemover => builder.clean(ControlCharRemover),
+            Step::ConsecutiveWhiteSpaceRemover => builder.clean(ConsecutiveWhiteSpaceRemover),
+            Step::SpaceNormalizer => builder.normalize(SpaceNormalizer),
+            Step::SpaceTrimmer => builder.clean(SpaceTrimmer),
+            Step::WhitespaceNormalizer => builder.clean(WhitespaceNormalizer),
+        }
+    }
+}
 
-    println!("{}", x);
+fn build_pipeline(steps: &[Step], language: &dyn LanguagePack) -> Pipeline {
+    steps
+        .iter()
+        .fold(Pipeline::builder(), |builder, step| {
+            step.add_to(builder, language)
+        })
+        .build()
 }
 
-/// Removes all unicode control characters
-pub struct ControlCharRemover;
+fn open_inputs(inputs: &[PathBuf]) -> io::Result<Box<dyn BufRead>> {
+    if inputs.is_empty() {
+        return Ok(Box::new(BufReader::new(io::stdin())));
+    }
 
-impl Clean for ControlCharRemover {
-    type Data = String;
+    let mut reader: Box<dyn Read> = Box::new(io::empty());
 
-    fn clean(&self, data: &mut Self::Data) {
-        data.retain(|c| !c.is_control());
+    for path in inputs {
+        reader = Box::new(reader.chain(File::open(path)?));
     }
+
+    Ok(Box::new(BufReader::new(reader)))
 }
 
-/// Normalizes all unicode characters to their canonical decomposition
-pub struct NfkcNormalizer;
+fn run(cli: &Cli) -> Result<(), Box<dyn Error>> {
+    let pipeline = match &cli.config {
+        Some(path) => PipelineConfig::from_path(path)?.build(),
+        None => {
+            let language = language_pack(&cli.lang)
+                .ok_or_else(|| format!("unknown language `{}`", cli.lang))?;
+
+            build_pipeline(&cli.steps, language)
+        }
+    };
 
-impl Normalize for NfkcNormalizer {
-    type Data = String;
+    let mut reader = open_inputs(&cli.inputs)?;
+
+    let mut writer: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout())),
+    };
+
+    if cli.whole {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+        pipeline.clean(&mut data);
+        writer.write_all(data.as_bytes())?;
+    } else {
+        for line in reader.lines() {
+            let mut data = line?;
+            pipeline.clean(&mut data);
+            writeln!(writer, "{}", data)?;
+        }
+    }
+
+    writer.flush()?;
+
+    Ok(())
+}
+
+fn main() {
+    let cli = Cli::parse();
 
-    fn normalize(&self, data: &mut Self::Data) {
-        *data = data.nfkc().collect::<String>();
+    if let Err(e) = run(&cli) {
+        eprintln!("error: {}", e);
+        std::process::exit(1);
     }
 }
 
-// pub struct Substitute {
-//     substitutions: vec![(vec![char], char)]
-// }
-
-// impl Substitute {
-//     pub fn new() -> Self {
-//         Substitute { substitutions: vec![] }
-//     }
-
-//     pub fn add(&mut self, from: Vec<char>, to: char) -> &mut Self {
-//         self.substitutions.push((from, to));
-//         self
-//     }
-
-//     pub fn append(&mut self, substitutions: vec![(vec![char], char)]) -> &mut Self {
-//         self.substitutions.append(&mut substitutions);
-//         self
-//     }
-// }
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_steps() {
+        let cli = Cli::parse_from(["textual-data-cleaner"]);
+        let mut data = "œﬃ «’eee\teee\t\t   eee".to_string();
+
+        build_pipeline(&cli.steps, language_pack(&cli.lang).unwrap()).clean(&mut data);
+
+        assert_eq!(data, "oeffi \"'eeeeee eee");
+    }
+
+    #[test]
+    fn test_lang() {
+        let cli = Cli::parse_from([
+            "textual-data-cleaner",
+            "--lang",
+            "de",
+            "-s",
+            "substitutions,typography",
+        ]);
+        let mut data = "„ Schöne Grüße ! “ – Œuvre’s".to_string();
+
+        build_pipeline(&cli.steps, language_pack(&cli.lang).unwrap()).clean(&mut data);
+
+        assert_eq!(data, "„Schöne Grüße!“ – OEuvre's");
+
+        assert!(Cli::try_parse_from(["textual-data-cleaner", "--lang", "xx"]).is_err());
+    }
+
+    #[test]
+    fn test_steps_order() {
+        let cli = Cli::parse_from([
+            "textual-data-cleaner",
+            "--step",
+            "space-normalizer,consecutive-white-space-remover",
+            "-s",
+            "space-trimmer",
+        ]);
+
+        assert_eq!(
+            cli.steps,
+            vec![
+                Step::SpaceNormalizer,
+                Step::ConsecutiveWhiteSpaceRemover,
+                Step::SpaceTrimmer
+            ]
+        );
+
+        let mut data = "\tlorem \t ipsum ".to_string();
+        build_pipeline(&cli.steps, language_pack(&cli.lang).unwrap()).clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum");
+    }
+}
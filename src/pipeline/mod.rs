@@ -0,0 +1,187 @@
+use std::sync::Arc;
+
+use crate::clean::Clean;
+use crate::normalize::Normalize;
+
+/// A step of a [`Pipeline`]
+#[derive(Clone)]
+enum Step {
+    Clean(Arc<dyn Clean<Data = String> + Send + Sync>),
+    Normalize(Arc<dyn Normalize<Data = String> + Send + Sync>),
+}
+
+impl Step {
+    fn apply(&self, data: &mut String) {
+        match self {
+            Step::Clean(cleaner) => cleaner.clean(data),
+            Step::Normalize(normalizer) => normalizer.normalize(data),
+        }
+    }
+}
+
+/// An ordered list of cleaners and normalizers applied one after another.
+///
+/// A pipeline is cheap to clone and can be shared across threads.
+///
+/// ```
+/// use textual_data_cleaner::common::ControlCharRemover;
+/// use textual_data_cleaner::pipeline::Pipeline;
+/// use textual_data_cleaner::whitespaces::{SpaceNormalizer, SpaceTrimmer};
+/// use textual_data_cleaner::Clean;
+///
+/// let pipeline = Pipeline::builder()
+///     .clean(ControlCharRemover)
+///     .normalize(SpaceNormalizer)
+///     .clean(SpaceTrimmer)
+///     .build();
+///
+/// let mut data = String::from(" lorem\u{0} ipsum\t");
+/// pipeline.clean(&mut data);
+///
+/// assert_eq!(data, "lorem ipsum");
+/// ```
+#[derive(Clone, Default)]
+pub struct Pipeline {
+    steps: Vec<Step>,
+}
+
+impl Pipeline {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn builder() -> PipelineBuilder {
+        PipelineBuilder::default()
+    }
+
+    /// Returns the number of steps in the pipeline
+    pub fn len(&self) -> usize {
+        self.steps.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+}
+
+impl Clean for Pipeline {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        for step in &self.steps {
+            step.apply(data);
+        }
+    }
+}
+
+/// Builds a [`Pipeline`] step by step
+#[derive(Clone, Default)]
+pub struct PipelineBuilder {
+    steps: Vec<Step>,
+}
+
+impl PipelineBuilder {
+    /// Appends a cleaner to the pipeline
+    pub fn clean<C>(mut self, cleaner: C) -> Self
+    where
+        C: Clean<Data = String> + Send + Sync + 'static,
+    {
+        self.steps.push(Step::Clean(Arc::new(cleaner)));
+        self
+    }
+
+    /// Appends a normalizer to the pipeline
+    pub fn normalize<N>(mut self, normalizer: N) -> Self
+    where
+        N: Normalize<Data = String> + Send + Sync + 'static,
+    {
+        self.steps.push(Step::Normalize(Arc::new(normalizer)));
+        self
+    }
+
+    /// Appends all the steps of another pipeline
+    pub fn pipeline(mut self, pipeline: &Pipeline) -> Self {
+        self.steps.extend(pipeline.steps.iter().cloned());
+        self
+    }
+
+    pub fn build(self) -> Pipeline {
+        Pipeline { steps: self.steps }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{AppendAfterCharIfDifferent, ControlCharRemover};
+    use crate::french::FrenchSubstitutions;
+    use crate::whitespaces::{ConsecutiveWhiteSpaceRemover, SpaceTrimmer};
+
+    #[test]
+    fn test_empty_pipeline() {
+        let mut data = String::from(" lorem\tipsum ");
+
+        Pipeline::new().clean(&mut data);
+
+        assert_eq!(data, " lorem\tipsum ");
+    }
+
+    #[test]
+    fn test_pipeline_order() {
+        let pipeline = Pipeline::builder()
+            .clean(FrenchSubstitutions::new())
+            .normalize(AppendAfterCharIfDifferent::new(';', ' '))
+            .clean(ConsecutiveWhiteSpaceRemover)
+            .clean(SpaceTrimmer)
+            .build();
+
+        assert_eq!(pipeline.len(), 4);
+
+        let mut data = String::from("  œuvre;  ");
+        pipeline.clean(&mut data);
+
+        assert_eq!(data, "oeuvre;");
+    }
+
+    #[test]
+    fn test_nested_pipeline() {
+        let inner = Pipeline::builder().clean(ControlCharRemover).build();
+
+        let pipeline = Pipeline::builder()
+            .pipeline(&inner)
+            .clean(inner.clone())
+            .clean(SpaceTrimmer)
+            .build();
+
+        assert_eq!(pipeline.len(), 3);
+
+        let mut data = String::from("\u{0} lorem ");
+        pipeline.clean(&mut data);
+
+        assert_eq!(data, "lorem");
+    }
+
+    #[test]
+    fn test_pipeline_across_threads() {
+        let pipeline = Pipeline::builder()
+            .clean(FrenchSubstitutions::new())
+            .clean(SpaceTrimmer)
+            .build();
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let pipeline = pipeline.clone();
+
+                std::thread::spawn(move || {
+                    let mut data = String::from(" Œuvre ");
+                    pipeline.clean(&mut data);
+                    data
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), "OEuvre");
+        }
+    }
+}
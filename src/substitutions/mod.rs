@@ -1,20 +1,76 @@
+use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::ops::RangeInclusive;
 
 use crate::clean::Clean;
+use crate::unicode::UnicodeScope;
 
-pub type SubstitutionsList = Vec<(RangeInclusive<char>, &'static str)>;
+mod regex;
+pub use self::regex::*;
 
+mod table;
+pub use table::*;
+
+/// What a substitution replaces: a range of chars or a string
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Pattern {
+    Chars(RangeInclusive<char>),
+    Str(String),
+}
+
+impl From<char> for Pattern {
+    fn from(c: char) -> Self {
+        Pattern::Chars(c..=c)
+    }
+}
+
+impl From<RangeInclusive<char>> for Pattern {
+    fn from(range: RangeInclusive<char>) -> Self {
+        Pattern::Chars(range)
+    }
+}
+
+impl From<&str> for Pattern {
+    fn from(pattern: &str) -> Self {
+        Pattern::Str(pattern.to_string())
+    }
+}
+
+pub type SubstitutionsList = Vec<(Pattern, &'static str)>;
+
+/// Builds a [`SubstitutionsList`] from chars, char ranges or strings mapped to a `&'static str`
+///
+/// ```
+/// use textual_data_cleaner::substitutions;
+///
+/// let list = substitutions! {
+///     'œ' => "oe",
+///     'à', 'â' => "a",
+///     '0'..='9' => "",
+///     "..." => "…",
+/// };
+///
+/// assert_eq!(list.len(), 5);
+/// ```
+#[macro_export]
 macro_rules! substitutions {
     ($($($substitution_list:expr),+ => $subst:expr),*  $(,)?) => {{
-        let mut x : Vec<(RangeInclusive<char>, &'static str)> = Vec::new();
+        use ::std::ops::RangeInclusive;
+        use $crate::substitutions::Pattern;
+
+        let mut x : Vec<(Pattern, &'static str)> = Vec::new();
         $(
             $(
                 if let Some(f) = (&$substitution_list as &dyn std::any::Any).downcast_ref::<char>() {
-                    x.push((*f ..=*f, $subst));
+                    x.push((Pattern::from(*f), $subst));
                 }
 
                 if let Some(f) = (&$substitution_list as &dyn std::any::Any).downcast_ref::<RangeInclusive<char>>() {
-                    x.push((f.clone(), $subst));
+                    x.push((Pattern::from(f.clone()), $subst));
+                }
+
+                if let Some(f) = (&$substitution_list as &dyn std::any::Any).downcast_ref::<&'static str>() {
+                    x.push((Pattern::from(*f), $subst));
                 }
             )*
         )*
@@ -23,38 +79,238 @@ macro_rules! substitutions {
     }};
 }
 
-pub(crate) use substitutions;
+/// Returns the char following `c`, skipping the surrogate code points
+fn next_char(c: char) -> Option<char> {
+    match c {
+        '\u{d7ff}' => Some('\u{e000}'),
+        _ => char::from_u32(c as u32 + 1),
+    }
+}
+
+/// Returns the char preceding `c`, skipping the surrogate code points
+fn previous_char(c: char) -> Option<char> {
+    match c {
+        '\u{e000}' => Some('\u{d7ff}'),
+        _ => (c as u32).checked_sub(1).and_then(char::from_u32),
+    }
+}
+
+/// Adds a substitution for the chars of a range that are not already covered by `intervals`
+fn insert_range(
+    intervals: &mut Vec<(char, char, usize)>,
+    start: char,
+    end: char,
+    substitution: usize,
+) {
+    // The intervals are sorted and disjoint, so the ones overlapping the range are contiguous
+    let first = intervals.partition_point(|(_, covered_end, _)| *covered_end < start);
+    let last = intervals.partition_point(|(covered_start, _, _)| *covered_start <= end);
+
+    let mut merged = Vec::with_capacity(2 * (last - first) + 1);
+    let mut cursor = Some(start);
+
+    for interval in &intervals[first..last] {
+        let (covered_start, covered_end, _) = *interval;
+
+        if let Some(from) = cursor {
+            if from < covered_start {
+                merged.push((
+                    from,
+                    previous_char(covered_start).unwrap_or(from),
+                    substitution,
+                ));
+            }
+
+            cursor = if covered_end >= end {
+                None
+            } else {
+                next_char(covered_end)
+            };
+        }
+
+        merged.push(*interval);
+    }
+
+    if let Some(from) = cursor {
+        merged.push((from, end, substitution));
+    }
+
+    intervals.splice(first..last, merged);
+}
 
-pub struct Substitute<'a> {
-    substitutions_list: &'a SubstitutionsList,
-    unicode_block_scope: &'a Option<String>,
+/// Substitute a set of chars or strings with a String
+///
+/// The substitutions, either a [`SubstitutionsList`] or a [`SubstitutionsTable`], are compiled
+/// once so a String is rewritten in a single pass, whatever the number of substitutions: char
+/// ranges become sorted, disjoint intervals and strings are indexed by their first char.
+///
+/// At each position the longest matching pattern wins, so a string pattern always takes
+/// precedence over a char pattern. When several patterns of the same length match, the first one
+/// wins. Replacements are never substituted again.
+///
+/// When a [`UnicodeScope`] is set with [`Substitute::unicode_block_scope`], only the chars within
+/// the scope are substituted; a string pattern is substituted only if all its chars are within
+/// the scope.
+#[derive(Clone, Debug)]
+pub struct Substitute {
+    replacements: Vec<String>,
+    ascii: [Option<usize>; 128],
+    intervals: Vec<(char, char, usize)>,
+    strings: HashMap<char, Vec<(String, usize)>>,
+    unicode_block_scope: Option<UnicodeScope>,
 }
 
-/// Substitute a set of chars with a &str
-impl<'a> Substitute<'a> {
-    pub fn new(
-        substitutions_list: &'a SubstitutionsList,
-        unicode_block_scope: &'a Option<String>,
-    ) -> Self {
+impl Substitute {
+    pub fn new<T: Into<SubstitutionsTable>>(substitutions: T) -> Self {
+        let table = substitutions.into();
+
+        let mut replacements = Vec::with_capacity(table.len());
+        let mut intervals: Vec<(char, char, usize)> = Vec::new();
+        let mut strings: HashMap<char, Vec<(String, usize)>> = HashMap::new();
+
+        for substitution in &table {
+            let index = replacements.len();
+            replacements.push(substitution.replacement().to_string());
+
+            match substitution {
+                Substitution::Char { char, .. } => {
+                    insert_range(&mut intervals, *char, *char, index)
+                }
+                Substitution::Range {
+                    range: (start, end),
+                    ..
+                } => {
+                    if start <= end {
+                        insert_range(&mut intervals, *start, *end, index)
+                    }
+                }
+                Substitution::Pattern { pattern, .. } => {
+                    let mut chars = pattern.chars();
+
+                    match (chars.next(), chars.next()) {
+                        (None, _) => {}
+                        (Some(c), None) => insert_range(&mut intervals, c, c, index),
+                        (Some(c), Some(_)) => {
+                            let candidates = strings.entry(c).or_default();
+
+                            if !candidates.iter().any(|(other, _)| other == pattern) {
+                                candidates.push((pattern.clone(), index));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Longest patterns first, the sort is stable so the order of the substitutions is kept otherwise
+        for candidates in strings.values_mut() {
+            candidates.sort_by_key(|(pattern, _)| std::cmp::Reverse(pattern.len()));
+        }
+
+        let mut ascii = [None; 128];
+
+        for (start, end, substitution) in &intervals {
+            for c in *start..=(*end).min('\u{7f}') {
+                ascii[c as usize] = Some(*substitution);
+            }
+        }
+
         Self {
-            substitutions_list,
-            unicode_block_scope,
+            replacements,
+            ascii,
+            intervals,
+            strings,
+            unicode_block_scope: None,
+        }
+    }
+
+    /// Only substitutes the chars within a unicode block or script
+    pub fn unicode_block_scope(mut self, scope: UnicodeScope) -> Self {
+        self.unicode_block_scope = Some(scope);
+        self
+    }
+
+    fn in_scope(&self, c: char) -> bool {
+        match &self.unicode_block_scope {
+            Some(scope) => scope.contains(c),
+            None => true,
+        }
+    }
+
+    /// Returns the substitution of a char, if any
+    pub fn substitution(&self, c: char) -> Option<&str> {
+        let substitution = if c.is_ascii() {
+            self.ascii[c as usize]
+        } else {
+            self.intervals
+                .binary_search_by(|(start, end, _)| {
+                    if *end < c {
+                        Ordering::Less
+                    } else if *start > c {
+                        Ordering::Greater
+                    } else {
+                        Ordering::Equal
+                    }
+                })
+                .ok()
+                .map(|index| self.intervals[index].2)
+        }?;
+
+        if self.in_scope(c) {
+            Some(&self.replacements[substitution])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the length in bytes and the substitution of the longest pattern matching the start
+    /// of `text`, if any
+    fn longest_match(&self, text: &str, c: char) -> Option<(usize, &str)> {
+        let string_match = self.strings.get(&c).and_then(|candidates| {
+            candidates.iter().find(|(pattern, _)| {
+                text.starts_with(pattern.as_str()) && pattern.chars().all(|c| self.in_scope(c))
+            })
+        });
+
+        match string_match {
+            Some((pattern, substitution)) => {
+                Some((pattern.len(), self.replacements[*substitution].as_str()))
+            }
+            None => self
+                .substitution(c)
+                .map(|substitution| (c.len_utf8(), substitution)),
         }
     }
 }
 
-impl<'a> Clean for Substitute<'a> {
+impl Clean for Substitute {
     type Data = String;
     fn clean(&self, data: &mut Self::Data) {
-        for (ranges, substitution) in self.substitutions_list.clone() {
-            for elem in ranges {
-                let mut last_index = data.len();
-                while let Some(i) = data[..last_index].rfind(elem) {
-                    data.replace_range(i..(i + elem.len_utf8()), &substitution); //replace_range = no allocation
-                    last_index = i;
+        // Most Strings don't need any substitution: `output` only allocates on the first one
+        let mut output = String::new();
+        let mut last_index = 0;
+        let mut index = 0;
+
+        while let Some(c) = data[index..].chars().next() {
+            match self.longest_match(&data[index..], c) {
+                Some((length, substitution)) => {
+                    if output.capacity() == 0 {
+                        output.reserve(data.len());
+                    }
+
+                    output.push_str(&data[last_index..index]);
+                    output.push_str(substitution);
+                    index += length;
+                    last_index = index;
                 }
+                None => index += c.len_utf8(),
             }
         }
+
+        if last_index > 0 {
+            output.push_str(&data[last_index..]);
+            *data = output;
+        }
     }
 }
 
@@ -68,7 +324,7 @@ mod tests {
             'à' => "a",
         );
 
-        let expected_1 = vec![('à'..='à', "a")];
+        let expected_1 = vec![(Pattern::from('à'..='à'), "a")];
 
         assert_eq!(s1, expected_1);
 
@@ -77,9 +333,20 @@ mod tests {
             'a' ..= 'z' => "",
         );
 
-        let expected_2 = vec![('à'..='à', "a"), ('a'..='z', "")];
+        let expected_2 = vec![
+            (Pattern::from('à'..='à'), "a"),
+            (Pattern::from('a'..='z'), ""),
+        ];
 
         assert_eq!(s2, expected_2);
+
+        let s3 = substitutions!(
+            "...", '…' => "…",
+        );
+
+        let expected_3 = vec![(Pattern::from("..."), "…"), (Pattern::from('…'), "…")];
+
+        assert_eq!(s3, expected_3);
     }
 
     #[test]
@@ -90,8 +357,134 @@ mod tests {
         );
 
         let mut input = "æ œ".to_string();
-        Substitute::new(&substitutions, &None).clean(&mut input);
+        Substitute::new(&substitutions).clean(&mut input);
 
         assert_eq!("ae oe", input);
     }
+
+    #[test]
+    fn test_substitute_ranges() {
+        let substitutions = substitutions!(
+            'a'..='z' => "",
+            '0'..='9' => "#",
+        );
+
+        let mut input = "Lorem 42 ipsum".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("L ## ", input);
+    }
+
+    #[test]
+    fn test_substitute_first_substitution_wins() {
+        let substitutions = substitutions!(
+            'e' => "é",
+            'a'..='z' => "_",
+            'x' => "y",
+            '\u{d000}'..='\u{f000}' => "?",
+        );
+
+        let mut input = "exa\u{d7ff}\u{e000}".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("é__??", input);
+    }
+
+    #[test]
+    fn test_substitute_replacements_are_not_substituted() {
+        let substitutions = substitutions!(
+            'œ' => "oe",
+            'o' => "0",
+        );
+
+        let mut input = "œ o".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("oe 0", input);
+    }
+
+    #[test]
+    fn test_substitute_nothing() {
+        let substitutions = substitutions!(
+            'œ' => "oe",
+        );
+
+        let mut input = "lorem ipsum".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("lorem ipsum", input);
+    }
+
+    #[test]
+    fn test_substitute_strings() {
+        let substitutions = substitutions!(
+            "..." => "…",
+            "--" => "—",
+            "&nbsp;" => " ",
+            "oe" => "œ",
+        );
+
+        let mut input = "coeur--soeur...&nbsp;ok".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("cœur—sœur… ok", input);
+    }
+
+    #[test]
+    fn test_substitute_longest_match_first() {
+        let substitutions = substitutions!(
+            '-' => "‐",
+            "--" => "–",
+            "---" => "—",
+            "œ" => "oe",
+            "œu" => "Œ",
+        );
+
+        let mut input = "a-b--c---d----eœuœ".to_string();
+        Substitute::new(&substitutions).clean(&mut input);
+
+        assert_eq!("a‐b–c—d—‐eŒoe", input);
+    }
+
+    #[test]
+    fn test_substitute_strings_in_scope() {
+        let substitutions = substitutions!(
+            "ﬁ." => "fi",
+            "ﬁﬁ" => "fifi",
+        );
+
+        let mut input = "ﬁ. ﬁﬁ".to_string();
+        let scope = UnicodeScope::block("Alphabetic Presentation Forms").unwrap();
+        Substitute::new(&substitutions)
+            .unicode_block_scope(scope)
+            .clean(&mut input);
+
+        assert_eq!("ﬁ. fifi", input);
+    }
+
+    #[test]
+    fn test_substitute_in_scope() {
+        let substitutions = substitutions!(
+            'ﬁ' => "fi",
+            'ﬀ' => "ff",
+            'β' => "b",
+            'в' => "v",
+        );
+
+        let mut input = "ﬁn ﬀ β в".to_string();
+        let scope = UnicodeScope::block("Alphabetic Presentation Forms").unwrap();
+        Substitute::new(&substitutions)
+            .unicode_block_scope(scope)
+            .clean(&mut input);
+
+        assert_eq!("fin ff β в", input);
+
+        let mut input = "ﬁn ﬀ β в".to_string();
+        let scope = UnicodeScope::script("Greek").unwrap().outside();
+        Substitute::new(&substitutions)
+            .unicode_block_scope(scope)
+            .clean(&mut input);
+
+        assert_eq!("fin ff β v", input);
+    }
 }
@@ -0,0 +1,101 @@
+use std::borrow::Cow;
+
+pub use ::regex::Error as RegexError;
+use ::regex::Regex;
+
+use crate::clean::Clean;
+
+/// Substitute the matches of a regular expression
+///
+/// The replacement can refer to capture groups with `$1` or `${name}`, see
+/// [`Regex::replace_all`].
+///
+/// ```
+/// use textual_data_cleaner::substitutions::RegexSubstitute;
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("le 18/10/2026 !!!");
+///
+/// RegexSubstitute::new(r"(\d{2})/(\d{2})/(\d{4})", "$3-$2-$1")
+///     .unwrap()
+///     .clean(&mut data);
+/// RegexSubstitute::new(r"([!?])+", "$1")
+///     .unwrap()
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "le 2026-10-18 !");
+/// ```
+#[derive(Clone, Debug)]
+pub struct RegexSubstitute {
+    regex: Regex,
+    replacement: String,
+}
+
+impl RegexSubstitute {
+    pub fn new(pattern: &str, replacement: &str) -> Result<Self, RegexError> {
+        Ok(Self {
+            regex: Regex::new(pattern)?,
+            replacement: replacement.to_string(),
+        })
+    }
+
+    pub fn regex(&self) -> &Regex {
+        &self.regex
+    }
+
+    pub fn replacement(&self) -> &str {
+        &self.replacement
+    }
+}
+
+impl Clean for RegexSubstitute {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        // `replace_all` borrows the data when nothing matches: no allocation in this case
+        if let Cow::Owned(substituted) = self.regex.replace_all(data, self.replacement.as_str()) {
+            *data = substituted;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_regex_substitute() {
+        let mut data = String::from("lorem - 12 - ipsum - 13 -");
+
+        RegexSubstitute::new(r"\s*- \d+ -", "")
+            .unwrap()
+            .clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum");
+    }
+
+    #[test]
+    fn test_regex_substitute_named_groups() {
+        let mut data = String::from("Hugo, Victor");
+
+        RegexSubstitute::new(r"(?P<last>\w+), (?P<first>\w+)", "${first} ${last}")
+            .unwrap()
+            .clean(&mut data);
+
+        assert_eq!(data, "Victor Hugo");
+    }
+
+    #[test]
+    fn test_regex_substitute_no_match() {
+        let mut data = String::from("lorem ipsum");
+
+        RegexSubstitute::new(r"\d+", "").unwrap().clean(&mut data);
+
+        assert_eq!(data, "lorem ipsum");
+    }
+
+    #[test]
+    fn test_invalid_regex() {
+        assert!(RegexSubstitute::new(r"(\d+", "").is_err());
+    }
+}
@@ -0,0 +1,234 @@
+use std::iter::FromIterator;
+use std::ops::RangeInclusive;
+
+use serde::{Deserialize, Serialize};
+
+use super::{Pattern, SubstitutionsList};
+
+/// An owned substitution of a single char, a range of chars or a string
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged, deny_unknown_fields)]
+pub enum Substitution {
+    Char {
+        char: char,
+        replacement: String,
+    },
+    Range {
+        range: (char, char),
+        replacement: String,
+    },
+    Pattern {
+        pattern: String,
+        replacement: String,
+    },
+}
+
+impl Substitution {
+    pub fn char(c: char, replacement: &str) -> Self {
+        Substitution::Char {
+            char: c,
+            replacement: replacement.to_string(),
+        }
+    }
+
+    pub fn range(range: RangeInclusive<char>, replacement: &str) -> Self {
+        Substitution::Range {
+            range: (*range.start(), *range.end()),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    pub fn pattern(pattern: &str, replacement: &str) -> Self {
+        Substitution::Pattern {
+            pattern: pattern.to_string(),
+            replacement: replacement.to_string(),
+        }
+    }
+
+    pub fn replacement(&self) -> &str {
+        match self {
+            Substitution::Char { replacement, .. }
+            | Substitution::Range { replacement, .. }
+            | Substitution::Pattern { replacement, .. } => replacement,
+        }
+    }
+}
+
+impl From<&(Pattern, &'static str)> for Substitution {
+    fn from((pattern, replacement): &(Pattern, &'static str)) -> Self {
+        match pattern {
+            Pattern::Chars(range) if range.start() == range.end() => {
+                Substitution::char(*range.start(), replacement)
+            }
+            Pattern::Chars(range) => Substitution::range(range.clone(), replacement),
+            Pattern::Str(pattern) => Substitution::pattern(pattern, replacement),
+        }
+    }
+}
+
+/// An owned list of substitutions that can be built at runtime and (de)serialized.
+///
+/// Like a [`SubstitutionsList`] the first substitution covering a char wins, so when merging
+/// tables the substitutions of the merged table only apply where the current ones don't.
+///
+/// ```
+/// use textual_data_cleaner::substitutions::{Substitute, Substitution, SubstitutionsTable};
+/// use textual_data_cleaner::{substitutions, Clean};
+///
+/// let mut table: SubstitutionsTable = serde_json::from_str(
+///     r#"[
+///         { "char": "œ", "replacement": "oe" },
+///         { "pattern": "&nbsp;", "replacement": " " }
+///     ]"#,
+/// )
+/// .unwrap();
+///
+/// table.push(Substitution::range('0'..='9', "#"));
+/// table.merge(&substitutions! { 'œ' => "?", 'æ' => "ae" }.into());
+///
+/// let mut data = String::from("œ&nbsp;æ 42");
+/// Substitute::new(&table).clean(&mut data);
+///
+/// assert_eq!(data, "oe ae ##");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct SubstitutionsTable {
+    substitutions: Vec<Substitution>,
+}
+
+impl SubstitutionsTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, substitution: Substitution) {
+        self.substitutions.push(substitution);
+    }
+
+    /// Appends the substitutions of another table, they have a lower priority than the current ones
+    pub fn merge(&mut self, other: &SubstitutionsTable) {
+        self.substitutions
+            .extend(other.substitutions.iter().cloned());
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, Substitution> {
+        self.substitutions.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.substitutions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.substitutions.is_empty()
+    }
+}
+
+impl From<Vec<Substitution>> for SubstitutionsTable {
+    fn from(substitutions: Vec<Substitution>) -> Self {
+        Self { substitutions }
+    }
+}
+
+impl From<&SubstitutionsList> for SubstitutionsTable {
+    fn from(list: &SubstitutionsList) -> Self {
+        list.iter().map(Substitution::from).collect()
+    }
+}
+
+impl From<SubstitutionsList> for SubstitutionsTable {
+    fn from(list: SubstitutionsList) -> Self {
+        Self::from(&list)
+    }
+}
+
+impl From<&SubstitutionsTable> for SubstitutionsTable {
+    fn from(table: &SubstitutionsTable) -> Self {
+        table.clone()
+    }
+}
+
+impl FromIterator<Substitution> for SubstitutionsTable {
+    fn from_iter<I: IntoIterator<Item = Substitution>>(iter: I) -> Self {
+        Self {
+            substitutions: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Substitution> for SubstitutionsTable {
+    fn extend<I: IntoIterator<Item = Substitution>>(&mut self, iter: I) {
+        self.substitutions.extend(iter);
+    }
+}
+
+impl<'a> IntoIterator for &'a SubstitutionsTable {
+    type Item = &'a Substitution;
+    type IntoIter = std::slice::Iter<'a, Substitution>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.substitutions.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::substitutions;
+
+    #[test]
+    fn test_table_from_list() {
+        let table = SubstitutionsTable::from(substitutions! {
+            'œ' => "oe",
+            'a'..='z' => "",
+            "..." => "…",
+        });
+
+        let expected: SubstitutionsTable = vec![
+            Substitution::char('œ', "oe"),
+            Substitution::range('a'..='z', ""),
+            Substitution::pattern("...", "…"),
+        ]
+        .into();
+
+        assert_eq!(table, expected);
+    }
+
+    #[test]
+    fn test_table_serialization() {
+        let table: SubstitutionsTable = vec![
+            Substitution::char('œ', "oe"),
+            Substitution::range('0'..='9', "#"),
+            Substitution::pattern("--", "—"),
+        ]
+        .into();
+
+        let json = serde_json::to_string(&table).unwrap();
+
+        assert_eq!(
+            json,
+            r##"[{"char":"œ","replacement":"oe"},{"range":["0","9"],"replacement":"#"},{"pattern":"--","replacement":"—"}]"##
+        );
+        assert_eq!(
+            serde_json::from_str::<SubstitutionsTable>(&json).unwrap(),
+            table
+        );
+    }
+
+    #[test]
+    fn test_table_merge() {
+        let mut table: SubstitutionsTable = vec![Substitution::char('œ', "oe")].into();
+        table.merge(&vec![Substitution::char('æ', "ae")].into());
+        table.extend(vec![Substitution::char('ß', "ss")]);
+
+        assert_eq!(table.len(), 3);
+        assert_eq!(
+            table
+                .iter()
+                .map(Substitution::replacement)
+                .collect::<Vec<_>>(),
+            vec!["oe", "ae", "ss"]
+        );
+    }
+}
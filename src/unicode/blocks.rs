@@ -0,0 +1,502 @@
+// Generated from the Unicode Character Database `Blocks.txt` (Unicode 14.0.0).
+// Surrogate blocks are left out since they can't be represented by a `char`.
+
+/// A named range of unicode code points
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnicodeBlock {
+    pub name: &'static str,
+    pub start: char,
+    pub end: char,
+}
+
+impl UnicodeBlock {
+    const fn new(name: &'static str, start: char, end: char) -> Self {
+        Self { name, start, end }
+    }
+
+    /// Returns the block matching a name.
+    ///
+    /// Names are compared ignoring case, whitespaces, hyphens and underscores, so
+    /// `Latin Extended-A` and `latin_extended_a` designate the same block.
+    pub fn from_name(name: &str) -> Option<&'static UnicodeBlock> {
+        let name = loose_name(name);
+
+        UNICODE_BLOCKS
+            .iter()
+            .find(|block| loose_name(block.name) == name)
+    }
+
+    /// Returns the block containing a char
+    pub fn of(c: char) -> Option<&'static UnicodeBlock> {
+        UNICODE_BLOCKS
+            .binary_search_by(|block| {
+                if block.end < c {
+                    std::cmp::Ordering::Less
+                } else if block.start > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|index| &UNICODE_BLOCKS[index])
+    }
+
+    pub fn contains(&self, c: char) -> bool {
+        self.start <= c && c <= self.end
+    }
+}
+
+fn loose_name(name: &str) -> String {
+    name.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// All the known unicode blocks, sorted by code point
+pub const UNICODE_BLOCKS: &[UnicodeBlock] = &[
+    UnicodeBlock::new("Basic Latin", '\u{0000}', '\u{007f}'),
+    UnicodeBlock::new("Latin-1 Supplement", '\u{0080}', '\u{00ff}'),
+    UnicodeBlock::new("Latin Extended-A", '\u{0100}', '\u{017f}'),
+    UnicodeBlock::new("Latin Extended-B", '\u{0180}', '\u{024f}'),
+    UnicodeBlock::new("IPA Extensions", '\u{0250}', '\u{02af}'),
+    UnicodeBlock::new("Spacing Modifier Letters", '\u{02b0}', '\u{02ff}'),
+    UnicodeBlock::new("Combining Diacritical Marks", '\u{0300}', '\u{036f}'),
+    UnicodeBlock::new("Greek and Coptic", '\u{0370}', '\u{03ff}'),
+    UnicodeBlock::new("Cyrillic", '\u{0400}', '\u{04ff}'),
+    UnicodeBlock::new("Cyrillic Supplement", '\u{0500}', '\u{052f}'),
+    UnicodeBlock::new("Armenian", '\u{0530}', '\u{058f}'),
+    UnicodeBlock::new("Hebrew", '\u{0590}', '\u{05ff}'),
+    UnicodeBlock::new("Arabic", '\u{0600}', '\u{06ff}'),
+    UnicodeBlock::new("Syriac", '\u{0700}', '\u{074f}'),
+    UnicodeBlock::new("Arabic Supplement", '\u{0750}', '\u{077f}'),
+    UnicodeBlock::new("Thaana", '\u{0780}', '\u{07bf}'),
+    UnicodeBlock::new("NKo", '\u{07c0}', '\u{07ff}'),
+    UnicodeBlock::new("Samaritan", '\u{0800}', '\u{083f}'),
+    UnicodeBlock::new("Mandaic", '\u{0840}', '\u{085f}'),
+    UnicodeBlock::new("Syriac Supplement", '\u{0860}', '\u{086f}'),
+    UnicodeBlock::new("Arabic Extended-B", '\u{0870}', '\u{089f}'),
+    UnicodeBlock::new("Arabic Extended-A", '\u{08a0}', '\u{08ff}'),
+    UnicodeBlock::new("Devanagari", '\u{0900}', '\u{097f}'),
+    UnicodeBlock::new("Bengali", '\u{0980}', '\u{09ff}'),
+    UnicodeBlock::new("Gurmukhi", '\u{0a00}', '\u{0a7f}'),
+    UnicodeBlock::new("Gujarati", '\u{0a80}', '\u{0aff}'),
+    UnicodeBlock::new("Oriya", '\u{0b00}', '\u{0b7f}'),
+    UnicodeBlock::new("Tamil", '\u{0b80}', '\u{0bff}'),
+    UnicodeBlock::new("Telugu", '\u{0c00}', '\u{0c7f}'),
+    UnicodeBlock::new("Kannada", '\u{0c80}', '\u{0cff}'),
+    UnicodeBlock::new("Malayalam", '\u{0d00}', '\u{0d7f}'),
+    UnicodeBlock::new("Sinhala", '\u{0d80}', '\u{0dff}'),
+    UnicodeBlock::new("Thai", '\u{0e00}', '\u{0e7f}'),
+    UnicodeBlock::new("Lao", '\u{0e80}', '\u{0eff}'),
+    UnicodeBlock::new("Tibetan", '\u{0f00}', '\u{0fff}'),
+    UnicodeBlock::new("Myanmar", '\u{1000}', '\u{109f}'),
+    UnicodeBlock::new("Georgian", '\u{10a0}', '\u{10ff}'),
+    UnicodeBlock::new("Hangul Jamo", '\u{1100}', '\u{11ff}'),
+    UnicodeBlock::new("Ethiopic", '\u{1200}', '\u{137f}'),
+    UnicodeBlock::new("Ethiopic Supplement", '\u{1380}', '\u{139f}'),
+    UnicodeBlock::new("Cherokee", '\u{13a0}', '\u{13ff}'),
+    UnicodeBlock::new(
+        "Unified Canadian Aboriginal Syllabics",
+        '\u{1400}',
+        '\u{167f}',
+    ),
+    UnicodeBlock::new("Ogham", '\u{1680}', '\u{169f}'),
+    UnicodeBlock::new("Runic", '\u{16a0}', '\u{16ff}'),
+    UnicodeBlock::new("Tagalog", '\u{1700}', '\u{171f}'),
+    UnicodeBlock::new("Hanunoo", '\u{1720}', '\u{173f}'),
+    UnicodeBlock::new("Buhid", '\u{1740}', '\u{175f}'),
+    UnicodeBlock::new("Tagbanwa", '\u{1760}', '\u{177f}'),
+    UnicodeBlock::new("Khmer", '\u{1780}', '\u{17ff}'),
+    UnicodeBlock::new("Mongolian", '\u{1800}', '\u{18af}'),
+    UnicodeBlock::new(
+        "Unified Canadian Aboriginal Syllabics Extended",
+        '\u{18b0}',
+        '\u{18ff}',
+    ),
+    UnicodeBlock::new("Limbu", '\u{1900}', '\u{194f}'),
+    UnicodeBlock::new("Tai Le", '\u{1950}', '\u{197f}'),
+    UnicodeBlock::new("New Tai Lue", '\u{1980}', '\u{19df}'),
+    UnicodeBlock::new("Khmer Symbols", '\u{19e0}', '\u{19ff}'),
+    UnicodeBlock::new("Buginese", '\u{1a00}', '\u{1a1f}'),
+    UnicodeBlock::new("Tai Tham", '\u{1a20}', '\u{1aaf}'),
+    UnicodeBlock::new(
+        "Combining Diacritical Marks Extended",
+        '\u{1ab0}',
+        '\u{1aff}',
+    ),
+    UnicodeBlock::new("Balinese", '\u{1b00}', '\u{1b7f}'),
+    UnicodeBlock::new("Sundanese", '\u{1b80}', '\u{1bbf}'),
+    UnicodeBlock::new("Batak", '\u{1bc0}', '\u{1bff}'),
+    UnicodeBlock::new("Lepcha", '\u{1c00}', '\u{1c4f}'),
+    UnicodeBlock::new("Ol Chiki", '\u{1c50}', '\u{1c7f}'),
+    UnicodeBlock::new("Cyrillic Extended-C", '\u{1c80}', '\u{1c8f}'),
+    UnicodeBlock::new("Georgian Extended", '\u{1c90}', '\u{1cbf}'),
+    UnicodeBlock::new("Sundanese Supplement", '\u{1cc0}', '\u{1ccf}'),
+    UnicodeBlock::new("Vedic Extensions", '\u{1cd0}', '\u{1cff}'),
+    UnicodeBlock::new("Phonetic Extensions", '\u{1d00}', '\u{1d7f}'),
+    UnicodeBlock::new("Phonetic Extensions Supplement", '\u{1d80}', '\u{1dbf}'),
+    UnicodeBlock::new(
+        "Combining Diacritical Marks Supplement",
+        '\u{1dc0}',
+        '\u{1dff}',
+    ),
+    UnicodeBlock::new("Latin Extended Additional", '\u{1e00}', '\u{1eff}'),
+    UnicodeBlock::new("Greek Extended", '\u{1f00}', '\u{1fff}'),
+    UnicodeBlock::new("General Punctuation", '\u{2000}', '\u{206f}'),
+    UnicodeBlock::new("Superscripts and Subscripts", '\u{2070}', '\u{209f}'),
+    UnicodeBlock::new("Currency Symbols", '\u{20a0}', '\u{20cf}'),
+    UnicodeBlock::new(
+        "Combining Diacritical Marks for Symbols",
+        '\u{20d0}',
+        '\u{20ff}',
+    ),
+    UnicodeBlock::new("Letterlike Symbols", '\u{2100}', '\u{214f}'),
+    UnicodeBlock::new("Number Forms", '\u{2150}', '\u{218f}'),
+    UnicodeBlock::new("Arrows", '\u{2190}', '\u{21ff}'),
+    UnicodeBlock::new("Mathematical Operators", '\u{2200}', '\u{22ff}'),
+    UnicodeBlock::new("Miscellaneous Technical", '\u{2300}', '\u{23ff}'),
+    UnicodeBlock::new("Control Pictures", '\u{2400}', '\u{243f}'),
+    UnicodeBlock::new("Optical Character Recognition", '\u{2440}', '\u{245f}'),
+    UnicodeBlock::new("Enclosed Alphanumerics", '\u{2460}', '\u{24ff}'),
+    UnicodeBlock::new("Box Drawing", '\u{2500}', '\u{257f}'),
+    UnicodeBlock::new("Block Elements", '\u{2580}', '\u{259f}'),
+    UnicodeBlock::new("Geometric Shapes", '\u{25a0}', '\u{25ff}'),
+    UnicodeBlock::new("Miscellaneous Symbols", '\u{2600}', '\u{26ff}'),
+    UnicodeBlock::new("Dingbats", '\u{2700}', '\u{27bf}'),
+    UnicodeBlock::new(
+        "Miscellaneous Mathematical Symbols-A",
+        '\u{27c0}',
+        '\u{27ef}',
+    ),
+    UnicodeBlock::new("Supplemental Arrows-A", '\u{27f0}', '\u{27ff}'),
+    UnicodeBlock::new("Braille Patterns", '\u{2800}', '\u{28ff}'),
+    UnicodeBlock::new("Supplemental Arrows-B", '\u{2900}', '\u{297f}'),
+    UnicodeBlock::new(
+        "Miscellaneous Mathematical Symbols-B",
+        '\u{2980}',
+        '\u{29ff}',
+    ),
+    UnicodeBlock::new(
+        "Supplemental Mathematical Operators",
+        '\u{2a00}',
+        '\u{2aff}',
+    ),
+    UnicodeBlock::new("Miscellaneous Symbols and Arrows", '\u{2b00}', '\u{2bff}'),
+    UnicodeBlock::new("Glagolitic", '\u{2c00}', '\u{2c5f}'),
+    UnicodeBlock::new("Latin Extended-C", '\u{2c60}', '\u{2c7f}'),
+    UnicodeBlock::new("Coptic", '\u{2c80}', '\u{2cff}'),
+    UnicodeBlock::new("Georgian Supplement", '\u{2d00}', '\u{2d2f}'),
+    UnicodeBlock::new("Tifinagh", '\u{2d30}', '\u{2d7f}'),
+    UnicodeBlock::new("Ethiopic Extended", '\u{2d80}', '\u{2ddf}'),
+    UnicodeBlock::new("Cyrillic Extended-A", '\u{2de0}', '\u{2dff}'),
+    UnicodeBlock::new("Supplemental Punctuation", '\u{2e00}', '\u{2e7f}'),
+    UnicodeBlock::new("CJK Radicals Supplement", '\u{2e80}', '\u{2eff}'),
+    UnicodeBlock::new("Kangxi Radicals", '\u{2f00}', '\u{2fdf}'),
+    UnicodeBlock::new("Ideographic Description Characters", '\u{2ff0}', '\u{2fff}'),
+    UnicodeBlock::new("CJK Symbols and Punctuation", '\u{3000}', '\u{303f}'),
+    UnicodeBlock::new("Hiragana", '\u{3040}', '\u{309f}'),
+    UnicodeBlock::new("Katakana", '\u{30a0}', '\u{30ff}'),
+    UnicodeBlock::new("Bopomofo", '\u{3100}', '\u{312f}'),
+    UnicodeBlock::new("Hangul Compatibility Jamo", '\u{3130}', '\u{318f}'),
+    UnicodeBlock::new("Kanbun", '\u{3190}', '\u{319f}'),
+    UnicodeBlock::new("Bopomofo Extended", '\u{31a0}', '\u{31bf}'),
+    UnicodeBlock::new("CJK Strokes", '\u{31c0}', '\u{31ef}'),
+    UnicodeBlock::new("Katakana Phonetic Extensions", '\u{31f0}', '\u{31ff}'),
+    UnicodeBlock::new("Enclosed CJK Letters and Months", '\u{3200}', '\u{32ff}'),
+    UnicodeBlock::new("CJK Compatibility", '\u{3300}', '\u{33ff}'),
+    UnicodeBlock::new("CJK Unified Ideographs Extension A", '\u{3400}', '\u{4dbf}'),
+    UnicodeBlock::new("Yijing Hexagram Symbols", '\u{4dc0}', '\u{4dff}'),
+    UnicodeBlock::new("CJK Unified Ideographs", '\u{4e00}', '\u{9fff}'),
+    UnicodeBlock::new("Yi Syllables", '\u{a000}', '\u{a48f}'),
+    UnicodeBlock::new("Yi Radicals", '\u{a490}', '\u{a4cf}'),
+    UnicodeBlock::new("Lisu", '\u{a4d0}', '\u{a4ff}'),
+    UnicodeBlock::new("Vai", '\u{a500}', '\u{a63f}'),
+    UnicodeBlock::new("Cyrillic Extended-B", '\u{a640}', '\u{a69f}'),
+    UnicodeBlock::new("Bamum", '\u{a6a0}', '\u{a6ff}'),
+    UnicodeBlock::new("Modifier Tone Letters", '\u{a700}', '\u{a71f}'),
+    UnicodeBlock::new("Latin Extended-D", '\u{a720}', '\u{a7ff}'),
+    UnicodeBlock::new("Syloti Nagri", '\u{a800}', '\u{a82f}'),
+    UnicodeBlock::new("Common Indic Number Forms", '\u{a830}', '\u{a83f}'),
+    UnicodeBlock::new("Phags-pa", '\u{a840}', '\u{a87f}'),
+    UnicodeBlock::new("Saurashtra", '\u{a880}', '\u{a8df}'),
+    UnicodeBlock::new("Devanagari Extended", '\u{a8e0}', '\u{a8ff}'),
+    UnicodeBlock::new("Kayah Li", '\u{a900}', '\u{a92f}'),
+    UnicodeBlock::new("Rejang", '\u{a930}', '\u{a95f}'),
+    UnicodeBlock::new("Hangul Jamo Extended-A", '\u{a960}', '\u{a97f}'),
+    UnicodeBlock::new("Javanese", '\u{a980}', '\u{a9df}'),
+    UnicodeBlock::new("Myanmar Extended-B", '\u{a9e0}', '\u{a9ff}'),
+    UnicodeBlock::new("Cham", '\u{aa00}', '\u{aa5f}'),
+    UnicodeBlock::new("Myanmar Extended-A", '\u{aa60}', '\u{aa7f}'),
+    UnicodeBlock::new("Tai Viet", '\u{aa80}', '\u{aadf}'),
+    UnicodeBlock::new("Meetei Mayek Extensions", '\u{aae0}', '\u{aaff}'),
+    UnicodeBlock::new("Ethiopic Extended-A", '\u{ab00}', '\u{ab2f}'),
+    UnicodeBlock::new("Latin Extended-E", '\u{ab30}', '\u{ab6f}'),
+    UnicodeBlock::new("Cherokee Supplement", '\u{ab70}', '\u{abbf}'),
+    UnicodeBlock::new("Meetei Mayek", '\u{abc0}', '\u{abff}'),
+    UnicodeBlock::new("Hangul Syllables", '\u{ac00}', '\u{d7af}'),
+    UnicodeBlock::new("Hangul Jamo Extended-B", '\u{d7b0}', '\u{d7ff}'),
+    UnicodeBlock::new("Private Use Area", '\u{e000}', '\u{f8ff}'),
+    UnicodeBlock::new("CJK Compatibility Ideographs", '\u{f900}', '\u{faff}'),
+    UnicodeBlock::new("Alphabetic Presentation Forms", '\u{fb00}', '\u{fb4f}'),
+    UnicodeBlock::new("Arabic Presentation Forms-A", '\u{fb50}', '\u{fdff}'),
+    UnicodeBlock::new("Variation Selectors", '\u{fe00}', '\u{fe0f}'),
+    UnicodeBlock::new("Vertical Forms", '\u{fe10}', '\u{fe1f}'),
+    UnicodeBlock::new("Combining Half Marks", '\u{fe20}', '\u{fe2f}'),
+    UnicodeBlock::new("CJK Compatibility Forms", '\u{fe30}', '\u{fe4f}'),
+    UnicodeBlock::new("Small Form Variants", '\u{fe50}', '\u{fe6f}'),
+    UnicodeBlock::new("Arabic Presentation Forms-B", '\u{fe70}', '\u{feff}'),
+    UnicodeBlock::new("Halfwidth and Fullwidth Forms", '\u{ff00}', '\u{ffef}'),
+    UnicodeBlock::new("Specials", '\u{fff0}', '\u{ffff}'),
+    UnicodeBlock::new("Linear B Syllabary", '\u{10000}', '\u{1007f}'),
+    UnicodeBlock::new("Linear B Ideograms", '\u{10080}', '\u{100ff}'),
+    UnicodeBlock::new("Aegean Numbers", '\u{10100}', '\u{1013f}'),
+    UnicodeBlock::new("Ancient Greek Numbers", '\u{10140}', '\u{1018f}'),
+    UnicodeBlock::new("Ancient Symbols", '\u{10190}', '\u{101cf}'),
+    UnicodeBlock::new("Phaistos Disc", '\u{101d0}', '\u{101ff}'),
+    UnicodeBlock::new("Lycian", '\u{10280}', '\u{1029f}'),
+    UnicodeBlock::new("Carian", '\u{102a0}', '\u{102df}'),
+    UnicodeBlock::new("Coptic Epact Numbers", '\u{102e0}', '\u{102ff}'),
+    UnicodeBlock::new("Old Italic", '\u{10300}', '\u{1032f}'),
+    UnicodeBlock::new("Gothic", '\u{10330}', '\u{1034f}'),
+    UnicodeBlock::new("Old Permic", '\u{10350}', '\u{1037f}'),
+    UnicodeBlock::new("Ugaritic", '\u{10380}', '\u{1039f}'),
+    UnicodeBlock::new("Old Persian", '\u{103a0}', '\u{103df}'),
+    UnicodeBlock::new("Deseret", '\u{10400}', '\u{1044f}'),
+    UnicodeBlock::new("Shavian", '\u{10450}', '\u{1047f}'),
+    UnicodeBlock::new("Osmanya", '\u{10480}', '\u{104af}'),
+    UnicodeBlock::new("Osage", '\u{104b0}', '\u{104ff}'),
+    UnicodeBlock::new("Elbasan", '\u{10500}', '\u{1052f}'),
+    UnicodeBlock::new("Caucasian Albanian", '\u{10530}', '\u{1056f}'),
+    UnicodeBlock::new("Vithkuqi", '\u{10570}', '\u{105bf}'),
+    UnicodeBlock::new("Linear A", '\u{10600}', '\u{1077f}'),
+    UnicodeBlock::new("Latin Extended-F", '\u{10780}', '\u{107bf}'),
+    UnicodeBlock::new("Cypriot Syllabary", '\u{10800}', '\u{1083f}'),
+    UnicodeBlock::new("Imperial Aramaic", '\u{10840}', '\u{1085f}'),
+    UnicodeBlock::new("Palmyrene", '\u{10860}', '\u{1087f}'),
+    UnicodeBlock::new("Nabataean", '\u{10880}', '\u{108af}'),
+    UnicodeBlock::new("Hatran", '\u{108e0}', '\u{108ff}'),
+    UnicodeBlock::new("Phoenician", '\u{10900}', '\u{1091f}'),
+    UnicodeBlock::new("Lydian", '\u{10920}', '\u{1093f}'),
+    UnicodeBlock::new("Meroitic Hieroglyphs", '\u{10980}', '\u{1099f}'),
+    UnicodeBlock::new("Meroitic Cursive", '\u{109a0}', '\u{109ff}'),
+    UnicodeBlock::new("Kharoshthi", '\u{10a00}', '\u{10a5f}'),
+    UnicodeBlock::new("Old South Arabian", '\u{10a60}', '\u{10a7f}'),
+    UnicodeBlock::new("Old North Arabian", '\u{10a80}', '\u{10a9f}'),
+    UnicodeBlock::new("Manichaean", '\u{10ac0}', '\u{10aff}'),
+    UnicodeBlock::new("Avestan", '\u{10b00}', '\u{10b3f}'),
+    UnicodeBlock::new("Inscriptional Parthian", '\u{10b40}', '\u{10b5f}'),
+    UnicodeBlock::new("Inscriptional Pahlavi", '\u{10b60}', '\u{10b7f}'),
+    UnicodeBlock::new("Psalter Pahlavi", '\u{10b80}', '\u{10baf}'),
+    UnicodeBlock::new("Old Turkic", '\u{10c00}', '\u{10c4f}'),
+    UnicodeBlock::new("Old Hungarian", '\u{10c80}', '\u{10cff}'),
+    UnicodeBlock::new("Hanifi Rohingya", '\u{10d00}', '\u{10d3f}'),
+    UnicodeBlock::new("Rumi Numeral Symbols", '\u{10e60}', '\u{10e7f}'),
+    UnicodeBlock::new("Yezidi", '\u{10e80}', '\u{10ebf}'),
+    UnicodeBlock::new("Old Sogdian", '\u{10f00}', '\u{10f2f}'),
+    UnicodeBlock::new("Sogdian", '\u{10f30}', '\u{10f6f}'),
+    UnicodeBlock::new("Old Uyghur", '\u{10f70}', '\u{10faf}'),
+    UnicodeBlock::new("Chorasmian", '\u{10fb0}', '\u{10fdf}'),
+    UnicodeBlock::new("Elymaic", '\u{10fe0}', '\u{10fff}'),
+    UnicodeBlock::new("Brahmi", '\u{11000}', '\u{1107f}'),
+    UnicodeBlock::new("Kaithi", '\u{11080}', '\u{110cf}'),
+    UnicodeBlock::new("Sora Sompeng", '\u{110d0}', '\u{110ff}'),
+    UnicodeBlock::new("Chakma", '\u{11100}', '\u{1114f}'),
+    UnicodeBlock::new("Mahajani", '\u{11150}', '\u{1117f}'),
+    UnicodeBlock::new("Sharada", '\u{11180}', '\u{111df}'),
+    UnicodeBlock::new("Sinhala Archaic Numbers", '\u{111e0}', '\u{111ff}'),
+    UnicodeBlock::new("Khojki", '\u{11200}', '\u{1124f}'),
+    UnicodeBlock::new("Multani", '\u{11280}', '\u{112af}'),
+    UnicodeBlock::new("Khudawadi", '\u{112b0}', '\u{112ff}'),
+    UnicodeBlock::new("Grantha", '\u{11300}', '\u{1137f}'),
+    UnicodeBlock::new("Newa", '\u{11400}', '\u{1147f}'),
+    UnicodeBlock::new("Tirhuta", '\u{11480}', '\u{114df}'),
+    UnicodeBlock::new("Siddham", '\u{11580}', '\u{115ff}'),
+    UnicodeBlock::new("Modi", '\u{11600}', '\u{1165f}'),
+    UnicodeBlock::new("Mongolian Supplement", '\u{11660}', '\u{1167f}'),
+    UnicodeBlock::new("Takri", '\u{11680}', '\u{116cf}'),
+    UnicodeBlock::new("Ahom", '\u{11700}', '\u{1174f}'),
+    UnicodeBlock::new("Dogra", '\u{11800}', '\u{1184f}'),
+    UnicodeBlock::new("Warang Citi", '\u{118a0}', '\u{118ff}'),
+    UnicodeBlock::new("Dives Akuru", '\u{11900}', '\u{1195f}'),
+    UnicodeBlock::new("Nandinagari", '\u{119a0}', '\u{119ff}'),
+    UnicodeBlock::new("Zanabazar Square", '\u{11a00}', '\u{11a4f}'),
+    UnicodeBlock::new("Soyombo", '\u{11a50}', '\u{11aaf}'),
+    UnicodeBlock::new(
+        "Unified Canadian Aboriginal Syllabics Extended-A",
+        '\u{11ab0}',
+        '\u{11abf}',
+    ),
+    UnicodeBlock::new("Pau Cin Hau", '\u{11ac0}', '\u{11aff}'),
+    UnicodeBlock::new("Bhaiksuki", '\u{11c00}', '\u{11c6f}'),
+    UnicodeBlock::new("Marchen", '\u{11c70}', '\u{11cbf}'),
+    UnicodeBlock::new("Masaram Gondi", '\u{11d00}', '\u{11d5f}'),
+    UnicodeBlock::new("Gunjala Gondi", '\u{11d60}', '\u{11daf}'),
+    UnicodeBlock::new("Makasar", '\u{11ee0}', '\u{11eff}'),
+    UnicodeBlock::new("Lisu Supplement", '\u{11fb0}', '\u{11fbf}'),
+    UnicodeBlock::new("Tamil Supplement", '\u{11fc0}', '\u{11fff}'),
+    UnicodeBlock::new("Cuneiform", '\u{12000}', '\u{123ff}'),
+    UnicodeBlock::new(
+        "Cuneiform Numbers and Punctuation",
+        '\u{12400}',
+        '\u{1247f}',
+    ),
+    UnicodeBlock::new("Early Dynastic Cuneiform", '\u{12480}', '\u{1254f}'),
+    UnicodeBlock::new("Cypro-Minoan", '\u{12f90}', '\u{12fff}'),
+    UnicodeBlock::new("Egyptian Hieroglyphs", '\u{13000}', '\u{1342f}'),
+    UnicodeBlock::new(
+        "Egyptian Hieroglyph Format Controls",
+        '\u{13430}',
+        '\u{1343f}',
+    ),
+    UnicodeBlock::new("Anatolian Hieroglyphs", '\u{14400}', '\u{1467f}'),
+    UnicodeBlock::new("Bamum Supplement", '\u{16800}', '\u{16a3f}'),
+    UnicodeBlock::new("Mro", '\u{16a40}', '\u{16a6f}'),
+    UnicodeBlock::new("Tangsa", '\u{16a70}', '\u{16acf}'),
+    UnicodeBlock::new("Bassa Vah", '\u{16ad0}', '\u{16aff}'),
+    UnicodeBlock::new("Pahawh Hmong", '\u{16b00}', '\u{16b8f}'),
+    UnicodeBlock::new("Medefaidrin", '\u{16e40}', '\u{16e9f}'),
+    UnicodeBlock::new("Miao", '\u{16f00}', '\u{16f9f}'),
+    UnicodeBlock::new(
+        "Ideographic Symbols and Punctuation",
+        '\u{16fe0}',
+        '\u{16fff}',
+    ),
+    UnicodeBlock::new("Tangut", '\u{17000}', '\u{187ff}'),
+    UnicodeBlock::new("Tangut Components", '\u{18800}', '\u{18aff}'),
+    UnicodeBlock::new("Khitan Small Script", '\u{18b00}', '\u{18cff}'),
+    UnicodeBlock::new("Tangut Supplement", '\u{18d00}', '\u{18d7f}'),
+    UnicodeBlock::new("Kana Extended-B", '\u{1aff0}', '\u{1afff}'),
+    UnicodeBlock::new("Kana Supplement", '\u{1b000}', '\u{1b0ff}'),
+    UnicodeBlock::new("Kana Extended-A", '\u{1b100}', '\u{1b12f}'),
+    UnicodeBlock::new("Small Kana Extension", '\u{1b130}', '\u{1b16f}'),
+    UnicodeBlock::new("Nushu", '\u{1b170}', '\u{1b2ff}'),
+    UnicodeBlock::new("Duployan", '\u{1bc00}', '\u{1bc9f}'),
+    UnicodeBlock::new("Shorthand Format Controls", '\u{1bca0}', '\u{1bcaf}'),
+    UnicodeBlock::new("Znamenny Musical Notation", '\u{1cf00}', '\u{1cfcf}'),
+    UnicodeBlock::new("Byzantine Musical Symbols", '\u{1d000}', '\u{1d0ff}'),
+    UnicodeBlock::new("Musical Symbols", '\u{1d100}', '\u{1d1ff}'),
+    UnicodeBlock::new("Ancient Greek Musical Notation", '\u{1d200}', '\u{1d24f}'),
+    UnicodeBlock::new("Mayan Numerals", '\u{1d2e0}', '\u{1d2ff}'),
+    UnicodeBlock::new("Tai Xuan Jing Symbols", '\u{1d300}', '\u{1d35f}'),
+    UnicodeBlock::new("Counting Rod Numerals", '\u{1d360}', '\u{1d37f}'),
+    UnicodeBlock::new(
+        "Mathematical Alphanumeric Symbols",
+        '\u{1d400}',
+        '\u{1d7ff}',
+    ),
+    UnicodeBlock::new("Sutton SignWriting", '\u{1d800}', '\u{1daaf}'),
+    UnicodeBlock::new("Latin Extended-G", '\u{1df00}', '\u{1dfff}'),
+    UnicodeBlock::new("Glagolitic Supplement", '\u{1e000}', '\u{1e02f}'),
+    UnicodeBlock::new("Nyiakeng Puachue Hmong", '\u{1e100}', '\u{1e14f}'),
+    UnicodeBlock::new("Toto", '\u{1e290}', '\u{1e2bf}'),
+    UnicodeBlock::new("Wancho", '\u{1e2c0}', '\u{1e2ff}'),
+    UnicodeBlock::new("Ethiopic Extended-B", '\u{1e7e0}', '\u{1e7ff}'),
+    UnicodeBlock::new("Mende Kikakui", '\u{1e800}', '\u{1e8df}'),
+    UnicodeBlock::new("Adlam", '\u{1e900}', '\u{1e95f}'),
+    UnicodeBlock::new("Indic Siyaq Numbers", '\u{1ec70}', '\u{1ecbf}'),
+    UnicodeBlock::new("Ottoman Siyaq Numbers", '\u{1ed00}', '\u{1ed4f}'),
+    UnicodeBlock::new(
+        "Arabic Mathematical Alphabetic Symbols",
+        '\u{1ee00}',
+        '\u{1eeff}',
+    ),
+    UnicodeBlock::new("Mahjong Tiles", '\u{1f000}', '\u{1f02f}'),
+    UnicodeBlock::new("Domino Tiles", '\u{1f030}', '\u{1f09f}'),
+    UnicodeBlock::new("Playing Cards", '\u{1f0a0}', '\u{1f0ff}'),
+    UnicodeBlock::new("Enclosed Alphanumeric Supplement", '\u{1f100}', '\u{1f1ff}'),
+    UnicodeBlock::new("Enclosed Ideographic Supplement", '\u{1f200}', '\u{1f2ff}'),
+    UnicodeBlock::new(
+        "Miscellaneous Symbols and Pictographs",
+        '\u{1f300}',
+        '\u{1f5ff}',
+    ),
+    UnicodeBlock::new("Emoticons", '\u{1f600}', '\u{1f64f}'),
+    UnicodeBlock::new("Ornamental Dingbats", '\u{1f650}', '\u{1f67f}'),
+    UnicodeBlock::new("Transport and Map Symbols", '\u{1f680}', '\u{1f6ff}'),
+    UnicodeBlock::new("Alchemical Symbols", '\u{1f700}', '\u{1f77f}'),
+    UnicodeBlock::new("Geometric Shapes Extended", '\u{1f780}', '\u{1f7ff}'),
+    UnicodeBlock::new("Supplemental Arrows-C", '\u{1f800}', '\u{1f8ff}'),
+    UnicodeBlock::new(
+        "Supplemental Symbols and Pictographs",
+        '\u{1f900}',
+        '\u{1f9ff}',
+    ),
+    UnicodeBlock::new("Chess Symbols", '\u{1fa00}', '\u{1fa6f}'),
+    UnicodeBlock::new(
+        "Symbols and Pictographs Extended-A",
+        '\u{1fa70}',
+        '\u{1faff}',
+    ),
+    UnicodeBlock::new("Symbols for Legacy Computing", '\u{1fb00}', '\u{1fbff}'),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension B",
+        '\u{20000}',
+        '\u{2a6df}',
+    ),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension C",
+        '\u{2a700}',
+        '\u{2b73f}',
+    ),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension D",
+        '\u{2b740}',
+        '\u{2b81f}',
+    ),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension E",
+        '\u{2b820}',
+        '\u{2ceaf}',
+    ),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension F",
+        '\u{2ceb0}',
+        '\u{2ebef}',
+    ),
+    UnicodeBlock::new(
+        "CJK Compatibility Ideographs Supplement",
+        '\u{2f800}',
+        '\u{2fa1f}',
+    ),
+    UnicodeBlock::new(
+        "CJK Unified Ideographs Extension G",
+        '\u{30000}',
+        '\u{3134f}',
+    ),
+    UnicodeBlock::new("Tags", '\u{e0000}', '\u{e007f}'),
+    UnicodeBlock::new("Variation Selectors Supplement", '\u{e0100}', '\u{e01ef}'),
+    UnicodeBlock::new("Supplementary Private Use Area-A", '\u{f0000}', '\u{fffff}'),
+    UnicodeBlock::new(
+        "Supplementary Private Use Area-B",
+        '\u{100000}',
+        '\u{10ffff}',
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_from_name() {
+        let block = UnicodeBlock::from_name("latin_extended_a").unwrap();
+
+        assert_eq!(block.name, "Latin Extended-A");
+        assert!(block.contains('œ'));
+        assert!(UnicodeBlock::from_name("Greek and Coptic").is_some());
+        assert!(UnicodeBlock::from_name("Klingon").is_none());
+    }
+
+    #[test]
+    fn test_block_of() {
+        assert_eq!(UnicodeBlock::of('a').unwrap().name, "Basic Latin");
+        assert_eq!(
+            UnicodeBlock::of('ﬃ').unwrap().name,
+            "Alphabetic Presentation Forms"
+        );
+        assert_eq!(UnicodeBlock::of('Ж').unwrap().name, "Cyrillic");
+        assert_eq!(UnicodeBlock::of('\u{40000}'), None);
+    }
+}
@@ -0,0 +1,400 @@
+// The categories are generated from the Unicode Character Database decomposition mappings
+// (Unicode 14.0.0): `<compat>` mappings of chars named `… LIGATURE …` for the ligatures,
+// `<wide>` and `<narrow>` for the width variants, `<super>` and `<sub>` for the superscripts and
+// subscripts, `<circle>`, `<square>` and the `<compat>` mappings of the enclosed blocks for the
+// enclosed forms and `<fraction>` for the fractions.
+
+use std::iter::once;
+
+use serde::Deserialize;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::clean::Clean;
+use crate::substitutions::{Substitute, Substitution, SubstitutionsTable};
+
+/// A category of compatibility mappings
+#[derive(Clone, Copy, Debug, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum CompatibilityCategory {
+    /// Ligatures such as `ﬁ` or `ĳ`
+    Ligatures,
+    /// Full-width and half-width forms such as `Ａ` or `ｶ`
+    Width,
+    /// Superscripts and subscripts such as `²`, `ª` or `₂`, including the signs `™` and `℠`,
+    /// which are folded to `TM` and `SM` unlike with the
+    /// [`SuperscriptConverter`](crate::unicode::SuperscriptConverter)
+    SuperscriptsSubscripts,
+    /// Circled, parenthesized and squared forms such as `①`, `⑴` or `🄰`
+    Enclosed,
+    /// Vulgar fractions such as `½`
+    Fractions,
+}
+
+impl CompatibilityCategory {
+    /// Returns the category of the compatibility mapping of a char, if any
+    pub fn of(c: char) -> Option<CompatibilityCategory> {
+        COMPATIBILITY_RANGES
+            .binary_search_by(|(start, end, _)| {
+                if *end < c {
+                    std::cmp::Ordering::Less
+                } else if *start > c {
+                    std::cmp::Ordering::Greater
+                } else {
+                    std::cmp::Ordering::Equal
+                }
+            })
+            .ok()
+            .map(|index| COMPATIBILITY_RANGES[index].2)
+    }
+}
+
+/// Applies the compatibility mappings (NFKC) of the chosen categories only
+///
+/// ```
+/// use textual_data_cleaner::unicode::{CompatibilityCategory, CompatibilityFolding};
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("ﬁn ① Ｈ₂Ｏ ½ m²");
+/// CompatibilityFolding::new()
+///     .with(CompatibilityCategory::Ligatures)
+///     .with(CompatibilityCategory::Width)
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "fin ① H₂O ½ m²");
+/// ```
+#[derive(Clone)]
+pub struct CompatibilityFolding {
+    categories: Vec<CompatibilityCategory>,
+    substitute: Substitute,
+}
+
+impl CompatibilityFolding {
+    /// Folding without any category
+    pub fn new() -> Self {
+        Self {
+            categories: Vec::new(),
+            substitute: Substitute::new(SubstitutionsTable::new()),
+        }
+    }
+
+    /// Applies the compatibility mappings of a category
+    pub fn with(mut self, category: CompatibilityCategory) -> Self {
+        if !self.categories.contains(&category) {
+            self.categories.push(category);
+            self.substitute = self.substitute();
+        }
+        self
+    }
+
+    /// Stops applying the compatibility mappings of a category
+    pub fn without(mut self, category: CompatibilityCategory) -> Self {
+        self.categories.retain(|enabled| *enabled != category);
+        self.substitute = self.substitute();
+        self
+    }
+
+    fn substitute(&self) -> Substitute {
+        let table: SubstitutionsTable = COMPATIBILITY_RANGES
+            .iter()
+            .filter(|(_, _, category)| self.categories.contains(category))
+            .flat_map(|(start, end, _)| *start..=*end)
+            .map(|c| Substitution::char(c, &once(c).nfkc().collect::<String>()))
+            .collect();
+
+        Substitute::new(table)
+    }
+}
+
+impl Default for CompatibilityFolding {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clean for CompatibilityFolding {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        self.substitute.clean(data);
+    }
+}
+
+/// Chars with a compatibility mapping, by category
+const COMPATIBILITY_RANGES: &[(char, char, CompatibilityCategory)] = &[
+    (
+        '\u{aa}',
+        '\u{aa}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{b2}',
+        '\u{b3}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{b9}',
+        '\u{ba}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{bc}', '\u{be}', CompatibilityCategory::Fractions),
+    ('\u{132}', '\u{133}', CompatibilityCategory::Ligatures),
+    (
+        '\u{2b0}',
+        '\u{2b8}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2e0}',
+        '\u{2e4}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{587}', '\u{587}', CompatibilityCategory::Ligatures),
+    (
+        '\u{10fc}',
+        '\u{10fc}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d2c}',
+        '\u{1d2e}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d30}',
+        '\u{1d3a}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d3c}',
+        '\u{1d4d}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d4f}',
+        '\u{1d6a}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d78}',
+        '\u{1d78}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{1d9b}',
+        '\u{1dbf}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2070}',
+        '\u{2071}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2074}',
+        '\u{208e}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2090}',
+        '\u{209c}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2120}',
+        '\u{2120}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2122}',
+        '\u{2122}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{2150}', '\u{215f}', CompatibilityCategory::Fractions),
+    ('\u{2189}', '\u{2189}', CompatibilityCategory::Fractions),
+    ('\u{2460}', '\u{24ea}', CompatibilityCategory::Enclosed),
+    (
+        '\u{2c7c}',
+        '\u{2c7d}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{2d6f}',
+        '\u{2d6f}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{3000}', '\u{3000}', CompatibilityCategory::Width),
+    (
+        '\u{3192}',
+        '\u{319f}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{3200}', '\u{321e}', CompatibilityCategory::Enclosed),
+    ('\u{3220}', '\u{3247}', CompatibilityCategory::Enclosed),
+    ('\u{3250}', '\u{327e}', CompatibilityCategory::Enclosed),
+    ('\u{3280}', '\u{3357}', CompatibilityCategory::Enclosed),
+    ('\u{3371}', '\u{33df}', CompatibilityCategory::Enclosed),
+    ('\u{33ff}', '\u{33ff}', CompatibilityCategory::Enclosed),
+    (
+        '\u{a69c}',
+        '\u{a69d}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{a770}',
+        '\u{a770}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{a7f2}',
+        '\u{a7f4}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{a7f8}',
+        '\u{a7f9}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{ab5c}',
+        '\u{ab5f}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{ab69}',
+        '\u{ab69}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{fb00}', '\u{fb06}', CompatibilityCategory::Ligatures),
+    ('\u{fb13}', '\u{fb17}', CompatibilityCategory::Ligatures),
+    ('\u{fb4f}', '\u{fb4f}', CompatibilityCategory::Ligatures),
+    ('\u{ff01}', '\u{ffbe}', CompatibilityCategory::Width),
+    ('\u{ffc2}', '\u{ffc7}', CompatibilityCategory::Width),
+    ('\u{ffca}', '\u{ffcf}', CompatibilityCategory::Width),
+    ('\u{ffd2}', '\u{ffd7}', CompatibilityCategory::Width),
+    ('\u{ffda}', '\u{ffdc}', CompatibilityCategory::Width),
+    ('\u{ffe0}', '\u{ffe6}', CompatibilityCategory::Width),
+    ('\u{ffe8}', '\u{ffee}', CompatibilityCategory::Width),
+    (
+        '\u{10781}',
+        '\u{10785}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{10787}',
+        '\u{107b0}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    (
+        '\u{107b2}',
+        '\u{107ba}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{1f100}', '\u{1f10a}', CompatibilityCategory::Enclosed),
+    ('\u{1f110}', '\u{1f12e}', CompatibilityCategory::Enclosed),
+    ('\u{1f130}', '\u{1f14f}', CompatibilityCategory::Enclosed),
+    (
+        '\u{1f16a}',
+        '\u{1f16c}',
+        CompatibilityCategory::SuperscriptsSubscripts,
+    ),
+    ('\u{1f190}', '\u{1f190}', CompatibilityCategory::Enclosed),
+    ('\u{1f200}', '\u{1f202}', CompatibilityCategory::Enclosed),
+    ('\u{1f210}', '\u{1f23b}', CompatibilityCategory::Enclosed),
+    ('\u{1f240}', '\u{1f248}', CompatibilityCategory::Enclosed),
+    ('\u{1f250}', '\u{1f251}', CompatibilityCategory::Enclosed),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fold(categories: &[CompatibilityCategory], input: &str) -> String {
+        let folding = categories
+            .iter()
+            .fold(CompatibilityFolding::new(), |folding, category| {
+                folding.with(*category)
+            });
+
+        let mut data = input.to_string();
+        folding.clean(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_category_of() {
+        assert_eq!(
+            CompatibilityCategory::of('ﬃ'),
+            Some(CompatibilityCategory::Ligatures)
+        );
+        assert_eq!(
+            CompatibilityCategory::of('ｶ'),
+            Some(CompatibilityCategory::Width)
+        );
+        assert_eq!(
+            CompatibilityCategory::of('ⁿ'),
+            Some(CompatibilityCategory::SuperscriptsSubscripts)
+        );
+        assert_eq!(
+            CompatibilityCategory::of('⑴'),
+            Some(CompatibilityCategory::Enclosed)
+        );
+        assert_eq!(
+            CompatibilityCategory::of('⅞'),
+            Some(CompatibilityCategory::Fractions)
+        );
+        assert_eq!(CompatibilityCategory::of('a'), None);
+        assert_eq!(CompatibilityCategory::of('é'), None);
+    }
+
+    #[test]
+    fn test_compatibility_folding() {
+        let input = "ﬃ Ｈｉ ｶ x² H₂O ⑫ Ⓐ ⑴ ¾";
+
+        assert_eq!(fold(&[], input), input);
+        assert_eq!(
+            fold(&[CompatibilityCategory::SuperscriptsSubscripts], input),
+            "ﬃ Ｈｉ ｶ x2 H2O ⑫ Ⓐ ⑴ ¾"
+        );
+        assert_eq!(
+            fold(
+                &[
+                    CompatibilityCategory::Enclosed,
+                    CompatibilityCategory::Fractions
+                ],
+                input
+            ),
+            "ﬃ Ｈｉ ｶ x² H₂O 12 A (1) 3\u{2044}4"
+        );
+        assert_eq!(
+            fold(
+                &[
+                    CompatibilityCategory::Ligatures,
+                    CompatibilityCategory::Width
+                ],
+                input
+            ),
+            "ffi Hi カ x² H₂O ⑫ Ⓐ ⑴ ¾"
+        );
+    }
+
+    #[test]
+    fn test_compatibility_folding_without() {
+        let mut data = "ﬁ ²".to_string();
+
+        CompatibilityFolding::new()
+            .with(CompatibilityCategory::Ligatures)
+            .with(CompatibilityCategory::SuperscriptsSubscripts)
+            .without(CompatibilityCategory::Ligatures)
+            .clean(&mut data);
+
+        assert_eq!(data, "ﬁ 2");
+    }
+
+    #[test]
+    fn test_compatibility_folding_signs() {
+        assert_eq!(
+            fold(&[CompatibilityCategory::SuperscriptsSubscripts], "Brand™ ℠"),
+            "BrandTM SM"
+        );
+    }
+}
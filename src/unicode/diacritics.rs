@@ -0,0 +1,151 @@
+use std::collections::HashSet;
+use std::iter::once;
+
+use unicode_general_category::{get_general_category, GeneralCategory};
+use unicode_normalization::{is_nfd_quick, IsNormalized, UnicodeNormalization};
+
+use crate::normalize::Normalize;
+
+/// Letters with a stroke, which have no canonical decomposition, and their base letter
+const STROKED_LETTERS: [(char, char); 14] = [
+    ('ø', 'o'),
+    ('Ø', 'O'),
+    ('ł', 'l'),
+    ('Ł', 'L'),
+    ('đ', 'd'),
+    ('Đ', 'D'),
+    ('ħ', 'h'),
+    ('Ħ', 'H'),
+    ('ŧ', 't'),
+    ('Ŧ', 'T'),
+    ('ƀ', 'b'),
+    ('Ƀ', 'B'),
+    ('ɨ', 'i'),
+    ('Ɨ', 'I'),
+];
+
+/// Returns true if the char is changed by the removal: it has a canonical decomposition, it is a
+/// nonspacing mark or a letter with a stroke
+fn has_diacritic(c: char) -> bool {
+    is_nfd_quick(once(c)) != IsNormalized::Yes
+        || get_general_category(c) == GeneralCategory::NonspacingMark
+        || STROKED_LETTERS.iter().any(|(letter, _)| *letter == c)
+}
+
+/// Removes the diacritics: the text is decomposed (NFD), the nonspacing marks are dropped and
+/// the text is recomposed (NFC)
+///
+/// The letters with a stroke `ø`, `ł`, `đ`, `ħ`, `ŧ`, `ƀ` and `ɨ` are replaced with their base
+/// letter too. The chars of the allow-list are kept with their diacritics, each case has to be
+/// listed.
+///
+/// ```
+/// use textual_data_cleaner::unicode::DiacriticRemover;
+/// use textual_data_cleaner::Normalize;
+///
+/// let mut data = String::from("Ça, l’année à Łódź, Ørsted");
+/// DiacriticRemover::new().normalize(&mut data);
+///
+/// assert_eq!(data, "Ca, l’annee a Lodz, Orsted");
+///
+/// let mut data = String::from("Ça, l’année à Łódź, Ørsted");
+/// DiacriticRemover::new().keep("çÇ".chars()).normalize(&mut data);
+///
+/// assert_eq!(data, "Ça, l’annee a Lodz, Orsted");
+/// ```
+#[derive(Clone)]
+pub struct DiacriticRemover {
+    allowed: HashSet<char>,
+}
+
+impl DiacriticRemover {
+    pub fn new() -> DiacriticRemover {
+        DiacriticRemover {
+            allowed: HashSet::new(),
+        }
+    }
+
+    /// Adds chars to the allow-list, in their composed form
+    pub fn keep<I: IntoIterator<Item = char>>(mut self, chars: I) -> Self {
+        self.allowed.extend(chars);
+        self
+    }
+}
+
+impl Default for DiacriticRemover {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Normalize for DiacriticRemover {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if !data.chars().any(has_diacritic) {
+            return;
+        }
+
+        let mut output = String::with_capacity(data.len());
+
+        for c in data.nfc() {
+            if self.allowed.contains(&c) {
+                output.push(c);
+            } else if let Some((_, base)) = STROKED_LETTERS.iter().find(|(letter, _)| *letter == c)
+            {
+                output.push(*base);
+            } else {
+                output.extend(
+                    once(c)
+                        .nfd()
+                        .filter(|d| get_general_category(*d) != GeneralCategory::NonspacingMark),
+                );
+            }
+        }
+
+        *data = output.nfc().collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remove(remover: &DiacriticRemover, input: &str) -> String {
+        let mut data = input.to_string();
+        remover.normalize(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_diacritic_removal() {
+        let remover = DiacriticRemover::new();
+
+        assert_eq!(
+            remove(&remover, "École, naïve, Mu\u{308}nchen, São Paulo, Đaković"),
+            "Ecole, naive, Munchen, Sao Paulo, Dakovic"
+        );
+        assert_eq!(remove(&remover, "Ἀθῆναι, Йошкар"), "Αθηναι, Иошкар");
+        assert_eq!(remove(&remover, "q\u{301} ñ"), "q n");
+        assert_eq!(remove(&remover, "한국어 ß æ"), "한국어 ß æ");
+        assert_eq!(remove(&remover, "Ħamrun, ŧ, ƀ, ɨ, Ɨ"), "Hamrun, t, b, i, I");
+    }
+
+    #[test]
+    fn test_allow_list() {
+        let remover = DiacriticRemover::new().keep(['ñ', 'Ñ', 'ø']);
+
+        assert_eq!(remove(&remover, "Ñandú, España"), "Ñandu, España");
+        assert_eq!(remove(&remover, "ø Ø"), "ø O");
+    }
+
+    #[test]
+    fn test_allow_list_decomposed_input() {
+        let remover = DiacriticRemover::new().keep(['ñ', 'Ñ']);
+
+        assert_eq!(
+            remove(&remover, "Espan\u{303}a, N\u{303}andu\u{301}, o\u{303}"),
+            "España, Ñandu, o"
+        );
+    }
+}
@@ -0,0 +1,108 @@
+use unicode_normalization::{is_nfc, is_nfd, is_nfkc, is_nfkd, UnicodeNormalization};
+
+mod blocks;
+pub use blocks::*;
+
+mod scope;
+pub use scope::*;
+
+mod compatibility;
+pub use compatibility::*;
+
+mod superscripts;
+pub use superscripts::*;
+
+mod diacritics;
+pub use diacritics::*;
+
+use crate::normalize::Normalize;
+
+/// Normalizes all unicode characters to their canonical decomposition followed by a canonical composition (NFC)
+///
+/// Like the other normalizers, the String is left untouched, without any allocation, when it is
+/// already normalized.
+pub struct NfcNormalizer;
+
+impl Normalize for NfcNormalizer {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if !is_nfc(data) {
+            *data = data.nfc().collect::<String>();
+        }
+    }
+}
+
+/// Normalizes all unicode characters to their canonical decomposition (NFD)
+pub struct NfdNormalizer;
+
+impl Normalize for NfdNormalizer {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if !is_nfd(data) {
+            *data = data.nfd().collect::<String>();
+        }
+    }
+}
+
+/// Normalizes all unicode characters to their compatibility decomposition followed by a canonical composition (NFKC)
+pub struct NfkcNormalizer;
+
+impl Normalize for NfkcNormalizer {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if !is_nfkc(data) {
+            *data = data.nfkc().collect::<String>();
+        }
+    }
+}
+
+/// Normalizes all unicode characters to their compatibility decomposition (NFKD)
+pub struct NfkdNormalizer;
+
+impl Normalize for NfkdNormalizer {
+    type Data = String;
+
+    fn normalize(&self, data: &mut Self::Data) {
+        if !is_nfkd(data) {
+            *data = data.nfkd().collect::<String>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn normalize<N: Normalize<Data = String>>(normalizer: N, input: &str) -> String {
+        let mut data = input.to_string();
+        normalizer.normalize(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_normalization_forms() {
+        let input = "e\u{301}ﬁ Å";
+
+        assert_eq!(normalize(NfcNormalizer, input), "\u{e9}ﬁ \u{c5}");
+        assert_eq!(normalize(NfdNormalizer, input), "e\u{301}ﬁ A\u{30a}");
+        assert_eq!(normalize(NfkcNormalizer, input), "\u{e9}fi \u{c5}");
+        assert_eq!(normalize(NfkdNormalizer, input), "e\u{301}fi A\u{30a}");
+    }
+
+    #[test]
+    fn test_normalized_input_is_not_reallocated() {
+        let mut data = String::with_capacity(64);
+        data.push_str("déjà normalisé");
+        let pointer = data.as_ptr();
+
+        NfcNormalizer.normalize(&mut data);
+        NfkcNormalizer.normalize(&mut data);
+
+        assert_eq!(data.as_ptr(), pointer);
+        assert_eq!(data.capacity(), 64);
+        assert_eq!(data, "déjà normalisé");
+    }
+}
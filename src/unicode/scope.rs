@@ -0,0 +1,124 @@
+use std::fmt;
+
+pub use unicode_script::Script;
+use unicode_script::UnicodeScript;
+
+use super::blocks::UnicodeBlock;
+
+/// Error raised when a unicode block or script name is unknown
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UnknownScopeError {
+    Block(String),
+    Script(String),
+}
+
+impl fmt::Display for UnknownScopeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnknownScopeError::Block(name) => write!(f, "unknown unicode block `{}`", name),
+            UnknownScopeError::Script(name) => write!(f, "unknown unicode script `{}`", name),
+        }
+    }
+}
+
+impl std::error::Error for UnknownScopeError {}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ScopeTarget {
+    Block(&'static UnicodeBlock),
+    Script(Script),
+}
+
+/// Restricts a cleaner to the chars within, or outside, a unicode block or script
+///
+/// ```
+/// use textual_data_cleaner::unicode::UnicodeScope;
+///
+/// let latin = UnicodeScope::script("Latin").unwrap();
+/// assert!(latin.contains('œ'));
+/// assert!(!latin.contains('α'));
+///
+/// let not_greek = UnicodeScope::block("Greek and Coptic").unwrap().outside();
+/// assert!(not_greek.contains('œ'));
+/// assert!(!not_greek.contains('α'));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct UnicodeScope {
+    target: ScopeTarget,
+    inside: bool,
+}
+
+impl UnicodeScope {
+    /// Scope covering the chars of a unicode block, see [`UnicodeBlock::from_name`]
+    pub fn block(name: &str) -> Result<Self, UnknownScopeError> {
+        UnicodeBlock::from_name(name)
+            .map(|block| Self {
+                target: ScopeTarget::Block(block),
+                inside: true,
+            })
+            .ok_or_else(|| UnknownScopeError::Block(name.to_string()))
+    }
+
+    /// Scope covering the chars of a unicode script, given by its full (`Latin`) or short (`Latn`) name
+    pub fn script(name: &str) -> Result<Self, UnknownScopeError> {
+        Script::from_full_name(name)
+            .or_else(|| Script::from_short_name(name))
+            .map(|script| Self {
+                target: ScopeTarget::Script(script),
+                inside: true,
+            })
+            .ok_or_else(|| UnknownScopeError::Script(name.to_string()))
+    }
+
+    /// Inverts the scope so it covers every char outside of the block or script
+    pub fn outside(mut self) -> Self {
+        self.inside = !self.inside;
+        self
+    }
+
+    /// Returns true if the char is within the scope
+    pub fn contains(&self, c: char) -> bool {
+        let in_target = match self.target {
+            ScopeTarget::Block(block) => block.contains(c),
+            ScopeTarget::Script(script) => c.script() == script,
+        };
+
+        in_target == self.inside
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_scope() {
+        let scope = UnicodeScope::block("Alphabetic Presentation Forms").unwrap();
+
+        assert!(scope.contains('ﬃ'));
+        assert!(!scope.contains('œ'));
+        assert!(scope.outside().contains('œ'));
+        assert!(!scope.outside().contains('ﬃ'));
+    }
+
+    #[test]
+    fn test_script_scope() {
+        let scope = UnicodeScope::script("Cyrl").unwrap();
+
+        assert!(scope.contains('Ж'));
+        assert!(!scope.contains('a'));
+        assert_eq!(scope, UnicodeScope::script("Cyrillic").unwrap());
+    }
+
+    #[test]
+    fn test_unknown_scope() {
+        assert_eq!(
+            UnicodeScope::block("Klingon"),
+            Err(UnknownScopeError::Block("Klingon".to_string()))
+        );
+        assert_eq!(
+            UnicodeScope::script("Klingon"),
+            Err(UnknownScopeError::Script("Klingon".to_string()))
+        );
+    }
+}
@@ -0,0 +1,236 @@
+use std::iter::once;
+
+use serde::Deserialize;
+use unicode_normalization::UnicodeNormalization;
+
+use super::CompatibilityCategory;
+use crate::clean::Clean;
+
+/// Notation of the superscripts and subscripts converted to plain chars
+#[derive(Clone, Copy, Debug, Default, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ScriptNotation {
+    /// Plain chars: `1ᵉʳ` to `1er`, `H₂O` to `H2O`
+    #[default]
+    Plain,
+    /// Caret notation: `1ᵉʳ` to `1^er`, `H₂O` to `H_2O`
+    Caret,
+    /// HTML markup: `1ᵉʳ` to `1<sup>er</sup>`, `H₂O` to `H<sub>2</sub>O`
+    Html,
+}
+
+/// Position of a char relative to the baseline
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Position {
+    Superscript,
+    Subscript,
+}
+
+impl Position {
+    /// Returns the position of the superscript and subscript digits, signs and letters
+    fn of(c: char) -> Option<Position> {
+        if CompatibilityCategory::of(c) != Some(CompatibilityCategory::SuperscriptsSubscripts) {
+            return None;
+        }
+
+        match c {
+            'ª' | 'º' | '¹' | '²' | '³' | '\u{2070}'..='\u{207f}' => {
+                Some(Position::Superscript)
+            }
+            '\u{1d62}'..='\u{1d6a}' | '\u{2080}'..='\u{209c}' | '\u{2c7c}' => {
+                Some(Position::Subscript)
+            }
+            _ => None,
+        }
+    }
+
+    fn open(self, notation: ScriptNotation) -> &'static str {
+        match (notation, self) {
+            (ScriptNotation::Plain, _) => "",
+            (ScriptNotation::Caret, Position::Superscript) => "^",
+            (ScriptNotation::Caret, Position::Subscript) => "_",
+            (ScriptNotation::Html, Position::Superscript) => "<sup>",
+            (ScriptNotation::Html, Position::Subscript) => "<sub>",
+        }
+    }
+
+    fn close(self, notation: ScriptNotation) -> &'static str {
+        match (notation, self) {
+            (ScriptNotation::Html, Position::Superscript) => "</sup>",
+            (ScriptNotation::Html, Position::Subscript) => "</sub>",
+            _ => "",
+        }
+    }
+}
+
+/// Returns true for the superscript modifier letters of the Latin and Greek scripts (`ᵉ`, `ʳ`,
+/// `ˢ`…), which are also used by the phonetic transcriptions (`kʰ`)
+fn is_modifier_letter(c: char) -> bool {
+    CompatibilityCategory::of(c) == Some(CompatibilityCategory::SuperscriptsSubscripts)
+        && matches!(c,
+            '\u{2b0}'..='\u{2b8}'
+            | '\u{2e0}'..='\u{2e4}'
+            | '\u{1d2c}'..='\u{1d61}'
+            | '\u{1d9b}'..='\u{1dbf}'
+            | '\u{2c7d}'
+            | '\u{a770}'
+            | '\u{a7f2}'..='\u{a7f4}'
+            | '\u{a7f8}'..='\u{a7f9}'
+            | '\u{ab5c}'..='\u{ab5f}'
+            | '\u{ab69}'
+            | '\u{10781}'..='\u{107ba}'
+        )
+}
+
+/// Converts the superscript and subscript chars (`¹`, `ᵉ`, `ʳ`, `₂`…) to plain chars
+///
+/// A run of consecutive superscripts, or subscripts, is marked once with the chosen
+/// [`ScriptNotation`], which keeps the abbreviations such as `1ᵉʳ` or `XIXᵉ` readable.
+///
+/// The superscript digits, signs and letters are always converted. The modifier letters (`ᵉ`,
+/// `ʳ`, `ʰ`…) are only converted in the abbreviations, after a digit, a capital letter or another
+/// superscript (`1ᵉʳ`, `Mˡˡᵉ`, `4ᵗʰ`), so that the phonetic transcriptions such as `kʰ` are left
+/// alone. The signs `™` and `℠`, and the modifier letters of the other scripts, are never
+/// converted, although the [`CompatibilityCategory::SuperscriptsSubscripts`] category of the
+/// [`CompatibilityFolding`](crate::unicode::CompatibilityFolding) folds them.
+///
+/// ```
+/// use textual_data_cleaner::unicode::{ScriptNotation, SuperscriptConverter};
+/// use textual_data_cleaner::Clean;
+///
+/// let mut data = String::from("XIXᵉ siècle, 1ᵉʳ vol., H₂O");
+/// SuperscriptConverter::new().clean(&mut data);
+///
+/// assert_eq!(data, "XIXe siècle, 1er vol., H2O");
+///
+/// let mut data = String::from("XIXᵉ siècle, 1ᵉʳ vol., H₂O");
+/// SuperscriptConverter::new()
+///     .notation(ScriptNotation::Html)
+///     .clean(&mut data);
+///
+/// assert_eq!(data, "XIX<sup>e</sup> siècle, 1<sup>er</sup> vol., H<sub>2</sub>O");
+/// ```
+#[derive(Clone)]
+pub struct SuperscriptConverter {
+    notation: ScriptNotation,
+}
+
+impl SuperscriptConverter {
+    pub fn new() -> SuperscriptConverter {
+        SuperscriptConverter {
+            notation: ScriptNotation::Plain,
+        }
+    }
+
+    pub fn notation(mut self, notation: ScriptNotation) -> Self {
+        self.notation = notation;
+        self
+    }
+}
+
+impl Default for SuperscriptConverter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clean for SuperscriptConverter {
+    type Data = String;
+
+    fn clean(&self, data: &mut Self::Data) {
+        if !data
+            .chars()
+            .any(|c| Position::of(c).is_some() || is_modifier_letter(c))
+        {
+            return;
+        }
+
+        let mut output = String::with_capacity(data.len() + 16);
+        let mut run: Option<Position> = None;
+        let mut previous_char: Option<char> = None;
+
+        for c in data.chars() {
+            let position = Position::of(c).or_else(|| {
+                let abbreviation = run == Some(Position::Superscript)
+                    || previous_char.is_some_and(|p| p.is_numeric() || p.is_uppercase());
+
+                (abbreviation && is_modifier_letter(c)).then_some(Position::Superscript)
+            });
+
+            if position != run {
+                if let Some(previous) = run {
+                    output.push_str(previous.close(self.notation));
+                }
+                if let Some(next) = position {
+                    output.push_str(next.open(self.notation));
+                }
+                run = position;
+            }
+
+            match position {
+                Some(_) => output.extend(once(c).nfkc()),
+                None => output.push(c),
+            }
+
+            previous_char = Some(c);
+        }
+
+        if let Some(previous) = run {
+            output.push_str(previous.close(self.notation));
+        }
+
+        *data = output;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn convert(notation: ScriptNotation, input: &str) -> String {
+        let mut data = input.to_string();
+        SuperscriptConverter::new()
+            .notation(notation)
+            .clean(&mut data);
+        data
+    }
+
+    #[test]
+    fn test_plain_notation() {
+        assert_eq!(
+            convert(ScriptNotation::Plain, "1ᵉʳ, 2ᵈ, 3ᵉ, Mˡˡᵉ, x⁽ⁿ⁺¹⁾, CO₂, aᵢⱼ"),
+            "1er, 2d, 3e, Mlle, x(n+1), CO2, aij"
+        );
+        assert_eq!(convert(ScriptNotation::Plain, "1ª 2º"), "1a 2o");
+    }
+
+    #[test]
+    fn test_caret_notation() {
+        assert_eq!(
+            convert(ScriptNotation::Caret, "1ᵉʳ, XIXᵉ, m², H₂O, x²₁"),
+            "1^er, XIX^e, m^2, H_2O, x^2_1"
+        );
+    }
+
+    #[test]
+    fn test_html_notation() {
+        assert_eq!(
+            convert(ScriptNotation::Html, "10ᵉ, m³, x₁ ²"),
+            "10<sup>e</sup>, m<sup>3</sup>, x<sub>1</sub> <sup>2</sup>"
+        );
+    }
+
+    #[test]
+    fn test_signs_and_phonetic_letters() {
+        assert_eq!(
+            convert(ScriptNotation::Caret, "Brand™ ℠ [kʰat] ʰ, 4ᵗʰ"),
+            "Brand™ ℠ [kʰat] ʰ, 4^th"
+        );
+    }
+
+    #[test]
+    fn test_without_scripts() {
+        let input = "aucun exposant ici ① ½";
+        assert_eq!(convert(ScriptNotation::Html, input), input);
+    }
+}
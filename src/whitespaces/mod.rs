@@ -10,8 +10,12 @@ impl Normalize for SpaceNormalizer {
     fn normalize(&self, data: &mut Self::Data) {
         let normal_space = " ";
         let mut last_index = data.len();
-        while let Some(i) = data[..last_index].rfind(char::is_whitespace) {
-            data.replace_range(i..(i + 1), &normal_space); //replace_range = no allocation
+        while let Some((i, c)) = data[..last_index]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+        {
+            data.replace_range(i..(i + c.len_utf8()), normal_space); //replace_range = no allocation
             last_index = i;
         }
     }
@@ -41,6 +45,7 @@ impl Clean for ConsecutiveWhiteSpaceRemover {
 }
 
 /// Removes leading and trailing spaces
+#[derive(Default)]
 pub struct SpaceTrimmer;
 
 impl SpaceTrimmer {
@@ -115,6 +120,17 @@ mod tests {
         assert_eq!(data, expected);
     }
 
+    #[test]
+    fn test_space_normalizer_multibyte_spaces() {
+        let input = "lorem\u{a0}ipsum\u{202f}!\u{3000}";
+        let expected = "lorem ipsum ! ";
+
+        let mut data = input.to_string();
+        SpaceNormalizer.normalize(&mut data);
+
+        assert_eq!(data, expected);
+    }
+
     #[test]
     fn test_whitespace_normalizer() {
         let input = "lorem ipsum dolor sit\t\n amet, consectetur adipiscing elit.\n ";